@@ -1,7 +1,7 @@
 use crate::ApiErrorComponent;
 #[cfg(feature = "actix")]
 use crate::{PathItemDefinition, ResponseWrapper};
-use apistos_models::paths::{MediaType, Parameter, RequestBody, Response, Responses};
+use apistos_models::paths::{Header, MediaType, Parameter, ParameterIn, RequestBody, Response, Responses};
 use apistos_models::reference_or::ReferenceOr;
 use apistos_models::security::SecurityScheme;
 use apistos_models::Schema;
@@ -37,6 +37,11 @@ pub trait ApiComponent {
     None
   }
 
+  /// Names of the securities which must all be satisfied, documented as a single security requirement.
+  fn security_requirement_names() -> Vec<String> {
+    Self::security_requirement_name().into_iter().collect()
+  }
+
   fn request_body() -> Option<RequestBody> {
     Self::schema().map(|(name, _)| RequestBody {
       content: BTreeMap::from_iter(vec![(
@@ -57,6 +62,11 @@ pub trait ApiComponent {
     vec![]
   }
 
+  /// Responses answered when this extractor rejects a request, only documented for handler arguments.
+  fn extractor_error_responses() -> Vec<(String, Response)> {
+    vec![]
+  }
+
   fn error_schemas() -> BTreeMap<String, (String, ReferenceOr<Schema>)> {
     BTreeMap::default()
   }
@@ -65,15 +75,25 @@ pub trait ApiComponent {
     None
   }
 
+  /// Response headers documented as components, referenced from the responses of this component.
+  fn header_components() -> Vec<(String, ReferenceOr<Header>)> {
+    vec![]
+  }
+
   fn parameters() -> Vec<Parameter> {
     vec![]
   }
 }
 
+/// Optional extractors never fail, they are documented as not required.
 impl<T> ApiComponent for Option<T>
 where
   T: ApiComponent,
 {
+  fn content_type() -> String {
+    T::content_type()
+  }
+
   fn required() -> bool {
     false
   }
@@ -97,6 +117,31 @@ where
   fn security_requirement_name() -> Option<String> {
     T::security_requirement_name()
   }
+
+  fn security_requirement_names() -> Vec<String> {
+    T::security_requirement_names()
+  }
+
+  fn request_body() -> Option<RequestBody> {
+    T::request_body().map(|request_body| RequestBody {
+      required: Some(false),
+      ..request_body
+    })
+  }
+
+  fn parameters() -> Vec<Parameter> {
+    T::parameters()
+      .into_iter()
+      .map(|parameter| match parameter._in {
+        // path parameters are always required
+        ParameterIn::Path => parameter,
+        _ => Parameter {
+          required: Some(false),
+          ..parameter
+        },
+      })
+      .collect()
+  }
 }
 
 impl<T> ApiComponent for Vec<T>
@@ -173,6 +218,12 @@ where
   fn responses(content_type: Option<String>) -> Option<Responses> {
     T::responses(content_type)
   }
+
+  fn header_components() -> Vec<(String, ReferenceOr<Header>)> {
+    let mut headers = T::header_components();
+    headers.append(&mut E::header_components());
+    headers
+  }
 }
 
 #[cfg(feature = "actix")]
@@ -197,6 +248,46 @@ impl<T: Clone> ApiComponent for actix_web::web::ReqData<T> {
   }
 }
 
+/// Responses are documented as `R` documents them. A status changed with `with_status` is only known at runtime and
+/// won't match the documented one, use `apistos::actix::WithStatus` instead.
+#[cfg(feature = "actix")]
+impl<R> ApiComponent for actix_web::CustomizeResponder<R>
+where
+  R: actix_web::Responder + ApiComponent,
+{
+  fn content_type() -> String {
+    R::content_type()
+  }
+
+  fn child_schemas() -> Vec<(String, ReferenceOr<Schema>)> {
+    R::child_schemas()
+  }
+
+  fn raw_schema() -> Option<ReferenceOr<Schema>> {
+    R::raw_schema()
+  }
+
+  fn schema() -> Option<(String, ReferenceOr<Schema>)> {
+    R::schema()
+  }
+
+  fn error_responses() -> Vec<(String, Response)> {
+    R::error_responses()
+  }
+
+  fn error_schemas() -> BTreeMap<String, (String, ReferenceOr<Schema>)> {
+    R::error_schemas()
+  }
+
+  fn header_components() -> Vec<(String, ReferenceOr<Header>)> {
+    R::header_components()
+  }
+
+  fn responses(content_type: Option<String>) -> Option<Responses> {
+    R::responses(content_type)
+  }
+}
+
 #[cfg(feature = "actix")]
 impl<F, R, P> ApiComponent for ResponseWrapper<F, P>
 where
@@ -224,59 +315,12 @@ where
     R::error_schemas()
   }
 
-  fn responses(content_type: Option<String>) -> Option<Responses> {
-    let mut responses = vec![];
-    if let Some(response) = R::responses(content_type.clone()) {
-      responses.append(
-        &mut response
-          .responses
-          .into_iter()
-          .collect::<Vec<(String, ReferenceOr<Response>)>>(),
-      );
-    } else if let Some((name, schema)) = Self::schema() {
-      let ref_or = match schema {
-        r @ ReferenceOr::Reference { .. } => r,
-        ReferenceOr::Object(schema_obj) => {
-          let _ref = ReferenceOr::Reference {
-            _ref: format!("#/components/schemas/{}", name),
-          };
-          match schema_obj {
-            Schema::Object(obj) => {
-              if obj.instance_type == Some(SingleOrVec::Single(Box::new(InstanceType::Array))) {
-                ReferenceOr::Object(Schema::Object(obj))
-              } else {
-                _ref
-              }
-            }
-            Schema::Bool(_) => _ref,
-          }
-        }
-      };
-      responses.push((
-        "200".to_owned(),
-        ReferenceOr::Object(Response {
-          content: BTreeMap::from_iter(vec![(
-            content_type.unwrap_or_else(Self::content_type),
-            MediaType {
-              schema: Some(ref_or),
-              ..Default::default()
-            },
-          )]),
-          ..Default::default()
-        }),
-      ));
-    } else if let Some(content_type) = content_type {
-      responses.push((
-        "200".to_owned(),
-        ReferenceOr::Object(Response {
-          content: BTreeMap::from_iter(vec![(content_type, MediaType::default())]),
-          ..Default::default()
-        }),
-      ));
-    } else {
-      responses.push(("200".to_owned(), ReferenceOr::Object(Response::default())));
-    }
+  fn header_components() -> Vec<(String, ReferenceOr<Header>)> {
+    R::header_components()
+  }
 
+  fn responses(content_type: Option<String>) -> Option<Responses> {
+    let mut responses = success_responses::<R>(content_type);
     responses.append(
       &mut Self::error_responses()
         .into_iter()
@@ -290,6 +334,52 @@ where
   }
 }
 
+/// Successful responses of a responder, inferred from its schema when it doesn't document its own responses.
+pub fn success_responses<T: ApiComponent>(content_type: Option<String>) -> Vec<(String, ReferenceOr<Response>)> {
+  if let Some(responses) = T::responses(content_type.clone()) {
+    return responses.responses.into_iter().collect();
+  }
+
+  let response = if let Some((name, schema)) = T::schema() {
+    let ref_or = match schema {
+      r @ ReferenceOr::Reference { .. } => r,
+      ReferenceOr::Object(schema_obj) => {
+        let _ref = ReferenceOr::Reference {
+          _ref: format!("#/components/schemas/{}", name),
+        };
+        match schema_obj {
+          Schema::Object(obj) => {
+            if obj.instance_type == Some(SingleOrVec::Single(Box::new(InstanceType::Array))) {
+              ReferenceOr::Object(Schema::Object(obj))
+            } else {
+              _ref
+            }
+          }
+          Schema::Bool(_) => _ref,
+        }
+      }
+    };
+    Response {
+      content: BTreeMap::from_iter(vec![(
+        content_type.unwrap_or_else(T::content_type),
+        MediaType {
+          schema: Some(ref_or),
+          ..Default::default()
+        },
+      )]),
+      ..Default::default()
+    }
+  } else if let Some(content_type) = content_type {
+    Response {
+      content: BTreeMap::from_iter(vec![(content_type, MediaType::default())]),
+      ..Default::default()
+    }
+  } else {
+    Response::default()
+  };
+  vec![("200".to_owned(), ReferenceOr::Object(response))]
+}
+
 #[cfg(test)]
 mod test {
   use crate::ApiComponent;
@@ -0,0 +1,10 @@
+use std::collections::BTreeMap;
+
+/// A closed set of documented oauth2 scopes, usually generated using `#[derive(ApiScopes)]` on an enum.
+pub trait ApiScopes {
+  /// Name of the scope as referenced by security requirements.
+  fn scope_name(&self) -> &'static str;
+
+  /// Every declared scope along with its description, meant to populate oauth2 flows.
+  fn scopes() -> BTreeMap<String, String>;
+}
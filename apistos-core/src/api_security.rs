@@ -0,0 +1,158 @@
+use crate::ApiComponent;
+use actix_web::error::ErrorUnauthorized;
+use actix_web::http::header::AUTHORIZATION;
+use actix_web::web::Query;
+use actix_web::HttpRequest;
+use apistos_models::security::{ApiKeyIn, SecurityScheme, SecurityType};
+use pin_project::pin_project;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Validate the credential read by an extractor generated with `#[openapi_security(extractor, ...)]`.
+///
+/// The credential is read from the documented location:
+/// - the header, query parameter or cookie named by `api_key(...)`
+/// - the `Authorization` header value following the `http(scheme = "...")` scheme, still encoded for `basic`
+/// - the `Authorization` header bearer token for `oauth2(...)` and `open_id_connect(...)`
+pub trait ApiSecurityValidator: Sized {
+  type Error: Into<actix_web::Error>;
+  type Future: Future<Output = Result<Self, Self::Error>>;
+
+  fn validate(req: &HttpRequest, credential: String) -> Self::Future;
+}
+
+/// Read the credential documented by `security_scheme` from the request.
+pub fn extract_credential(req: &HttpRequest, security_scheme: &SecurityScheme) -> Option<String> {
+  let (name, _in) = match &security_scheme._type {
+    SecurityType::ApiKey(api_key) => (api_key.name.as_str(), &api_key._in),
+    SecurityType::Http(http) => return authorization_credential(req, &http.scheme),
+    SecurityType::OAuth2(_) | SecurityType::OpenIdConnect(_) => return authorization_credential(req, "bearer"),
+  };
+  match _in {
+    ApiKeyIn::Header => req
+      .headers()
+      .get(name)
+      .and_then(|value| value.to_str().ok())
+      .map(ToOwned::to_owned),
+    ApiKeyIn::Query => Query::<HashMap<String, String>>::from_query(req.query_string())
+      .ok()
+      .and_then(|query| query.into_inner().remove(name)),
+    ApiKeyIn::Cookie => req.cookie(name).map(|cookie| cookie.value().to_owned()),
+  }
+}
+
+fn authorization_credential(req: &HttpRequest, scheme: &str) -> Option<String> {
+  let authorization = req.headers().get(AUTHORIZATION)?.to_str().ok()?;
+  let (request_scheme, credential) = authorization.split_once(' ')?;
+  request_scheme
+    .eq_ignore_ascii_case(scheme)
+    .then(|| credential.trim().to_owned())
+    .filter(|credential| !credential.is_empty())
+}
+
+/// Extract the credential documented by `T` and validate it, answering `401 Unauthorized` when it is missing.
+pub fn validate_security<T>(req: &HttpRequest) -> ApiSecurityFuture<T::Future>
+where
+  T: ApiSecurityValidator + ApiComponent,
+{
+  let credential = T::securities()
+    .values()
+    .find_map(|security_scheme| extract_credential(req, security_scheme));
+  match credential {
+    Some(credential) => ApiSecurityFuture::Validating(T::validate(req, credential)),
+    None => {
+      let names = T::security_requirement_names().join(", ");
+      ApiSecurityFuture::Missing(Some(ErrorUnauthorized(format!("Missing `{names}` credentials"))))
+    }
+  }
+}
+
+#[pin_project(project = ApiSecurityFutureProj)]
+pub enum ApiSecurityFuture<F> {
+  Missing(Option<actix_web::Error>),
+  Validating(#[pin] F),
+}
+
+impl<F, T, E> Future for ApiSecurityFuture<F>
+where
+  F: Future<Output = Result<T, E>>,
+  E: Into<actix_web::Error>,
+{
+  type Output = Result<T, actix_web::Error>;
+
+  fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    match self.project() {
+      ApiSecurityFutureProj::Missing(error) => Poll::Ready(Err(
+        error.take().unwrap_or_else(|| ErrorUnauthorized("Missing credentials")),
+      )),
+      ApiSecurityFutureProj::Validating(future) => future.poll(cx).map(|res| res.map_err(Into::into)),
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::api_security::extract_credential;
+  use actix_web::cookie::Cookie;
+  use actix_web::test::TestRequest;
+  use apistos_models::security::{ApiKey, ApiKeyIn, Http, SecurityScheme, SecurityType};
+
+  fn security_scheme(_type: SecurityType) -> SecurityScheme {
+    SecurityScheme {
+      _type,
+      description: None,
+      extensions: Default::default(),
+    }
+  }
+
+  #[test]
+  fn extract_api_key_credential() {
+    let api_key = |_in: ApiKeyIn| {
+      security_scheme(SecurityType::ApiKey(ApiKey {
+        name: "api_key".to_owned(),
+        _in,
+      }))
+    };
+
+    let req = TestRequest::default()
+      .insert_header(("api_key", "from-header"))
+      .uri("/?api_key=from%20query")
+      .cookie(Cookie::new("api_key", "from-cookie"))
+      .to_http_request();
+    assert_eq!(
+      extract_credential(&req, &api_key(ApiKeyIn::Header)).as_deref(),
+      Some("from-header")
+    );
+    assert_eq!(
+      extract_credential(&req, &api_key(ApiKeyIn::Query)).as_deref(),
+      Some("from query")
+    );
+    assert_eq!(
+      extract_credential(&req, &api_key(ApiKeyIn::Cookie)).as_deref(),
+      Some("from-cookie")
+    );
+
+    let req = TestRequest::default().to_http_request();
+    assert_eq!(extract_credential(&req, &api_key(ApiKeyIn::Header)), None);
+  }
+
+  #[test]
+  fn extract_http_credential() {
+    let bearer = security_scheme(SecurityType::Http(Http {
+      scheme: "bearer".to_owned(),
+      bearer_format: None,
+    }));
+
+    let req = TestRequest::default()
+      .insert_header(("Authorization", "Bearer token"))
+      .to_http_request();
+    assert_eq!(extract_credential(&req, &bearer).as_deref(), Some("token"));
+
+    let req = TestRequest::default()
+      .insert_header(("Authorization", "Basic dXNlcjpwYXNz"))
+      .to_http_request();
+    assert_eq!(extract_credential(&req, &bearer), None);
+  }
+}
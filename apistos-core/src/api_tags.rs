@@ -0,0 +1,10 @@
+use apistos_models::tag::Tag;
+
+/// A closed set of documented tags, usually generated using `#[derive(ApiTags)]` on an enum.
+pub trait ApiTags {
+  /// Name of the tag as referenced by operations.
+  fn tag_name(&self) -> &'static str;
+
+  /// Every declared tag along with its documentation, meant to populate `Spec.tags`.
+  fn tags() -> Vec<Tag>;
+}
@@ -0,0 +1,100 @@
+use crate::request_body::merge_request_bodies;
+use crate::{insert_response, success_responses, ApiComponent};
+use actix_web::Either;
+use apistos_models::paths::{Header, Parameter, RequestBody, Response, Responses};
+use apistos_models::reference_or::ReferenceOr;
+use apistos_models::Schema;
+use std::collections::BTreeMap;
+
+/// Alternative extractors, the request bodies being merged into a single one documenting every accepted media type.
+///
+/// Alternative responders are documented with the responses of both alternatives, using a `oneOf` when both answer
+/// with the same status.
+impl<L, R> ApiComponent for Either<L, R>
+where
+  L: ApiComponent,
+  R: ApiComponent,
+{
+  fn content_type() -> String {
+    L::content_type()
+  }
+
+  fn required() -> bool {
+    L::required() && R::required()
+  }
+
+  fn child_schemas() -> Vec<(String, ReferenceOr<Schema>)> {
+    let mut schemas = L::child_schemas();
+    schemas.extend(R::child_schemas());
+    schemas.extend(L::schema());
+    schemas.extend(R::schema());
+    schemas
+  }
+
+  fn schema() -> Option<(String, ReferenceOr<Schema>)> {
+    None
+  }
+
+  fn request_body() -> Option<RequestBody> {
+    match (L::request_body(), R::request_body()) {
+      (Some(left), Some(right)) => Some(merge_request_bodies(left, right)),
+      (left, right) => left.or(right),
+    }
+  }
+
+  fn error_responses() -> Vec<(String, Response)> {
+    let mut responses = L::error_responses();
+    for (status, response) in R::error_responses() {
+      if !responses.iter().any(|(s, _)| *s == status) {
+        responses.push((status, response));
+      }
+    }
+    responses
+  }
+
+  fn extractor_error_responses() -> Vec<(String, Response)> {
+    let mut responses = L::extractor_error_responses();
+    for (status, response) in R::extractor_error_responses() {
+      if !responses.iter().any(|(s, _)| *s == status) {
+        responses.push((status, response));
+      }
+    }
+    responses
+  }
+
+  fn error_schemas() -> BTreeMap<String, (String, ReferenceOr<Schema>)> {
+    let mut schemas = R::error_schemas();
+    schemas.extend(L::error_schemas());
+    schemas
+  }
+
+  fn header_components() -> Vec<(String, ReferenceOr<Header>)> {
+    let mut headers = L::header_components();
+    headers.append(&mut R::header_components());
+    headers
+  }
+
+  fn responses(content_type: Option<String>) -> Option<Responses> {
+    let mut responses = BTreeMap::from_iter(success_responses::<L>(content_type.clone()));
+    for (status, response) in success_responses::<R>(content_type) {
+      insert_response(&mut responses, status, response);
+    }
+    Some(Responses {
+      responses,
+      ..Default::default()
+    })
+  }
+
+  fn parameters() -> Vec<Parameter> {
+    let mut parameters = L::parameters();
+    for parameter in R::parameters() {
+      if !parameters
+        .iter()
+        .any(|p| p.name == parameter.name && p._in == parameter._in)
+      {
+        parameters.push(parameter);
+      }
+    }
+    parameters
+  }
+}
@@ -1,5 +1,10 @@
+use crate::extractor_errors::json_payload_error_responses;
+#[cfg(feature = "garde")]
+use crate::extractor_errors::validated;
 use crate::ApiComponent;
+use actix_web::error::UrlencodedError;
 use actix_web::web::Form;
+use apistos_models::paths::{Parameter, ParameterIn, RequestBody, Response};
 use apistos_models::reference_or::ReferenceOr;
 use apistos_models::Schema;
 
@@ -18,8 +23,14 @@ where
   fn schema() -> Option<(String, ReferenceOr<Schema>)> {
     T::schema()
   }
+
+  fn extractor_error_responses() -> Vec<(String, Response)> {
+    json_payload_error_responses()
+  }
 }
 
+impl_fallible_extractor!(Form, UrlencodedError);
+
 #[cfg(feature = "garde")]
 impl<T> ApiComponent for garde_actix_web::web::Form<T>
 where
@@ -36,4 +47,8 @@ where
   fn schema() -> Option<(String, ReferenceOr<Schema>)> {
     T::schema()
   }
+
+  fn extractor_error_responses() -> Vec<(String, Response)> {
+    validated(json_payload_error_responses())
+  }
 }
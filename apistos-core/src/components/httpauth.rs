@@ -0,0 +1,284 @@
+use crate::ApiComponent;
+use actix_web::dev::Payload;
+use actix_web::{FromRequest, HttpRequest};
+use actix_web_httpauth::extractors::basic::BasicAuth;
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use apistos_models::reference_or::ReferenceOr;
+use apistos_models::security::{Http, SecurityScheme, SecurityType};
+use apistos_models::Schema;
+use pin_project::pin_project;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Documentation of an `actix-web-httpauth` extractor wrapped in [`HttpAuth`].
+///
+/// ```rust
+/// use actix_web_httpauth::extractors::bearer::BearerAuth;
+/// use apistos_core::httpauth::{HttpAuth, HttpAuthScheme};
+///
+/// pub struct Jwt;
+///
+/// impl HttpAuthScheme for Jwt {
+///   fn name() -> String {
+///     "jwt".to_string()
+///   }
+///
+///   fn bearer_format() -> Option<String> {
+///     Some("JWT".to_string())
+///   }
+/// }
+///
+/// pub type JwtAuth = HttpAuth<BearerAuth, Jwt>;
+/// ```
+pub trait HttpAuthScheme {
+  /// Name of the security scheme in `components.securitySchemes`
+  fn name() -> String;
+
+  /// Hint on how the bearer token is formatted, e.g. `JWT`. Ignored for basic authentication.
+  fn bearer_format() -> Option<String> {
+    None
+  }
+
+  fn description() -> Option<String> {
+    None
+  }
+}
+
+/// Default documentation of [`BearerAuth`], registered as `bearer_auth`.
+pub struct DefaultBearerScheme;
+
+impl HttpAuthScheme for DefaultBearerScheme {
+  fn name() -> String {
+    "bearer_auth".to_string()
+  }
+}
+
+/// Default documentation of [`BasicAuth`], registered as `basic_auth`.
+pub struct DefaultBasicScheme;
+
+impl HttpAuthScheme for DefaultBasicScheme {
+  fn name() -> String {
+    "basic_auth".to_string()
+  }
+}
+
+/// Extractor wrapping an `actix-web-httpauth` extractor to document it with a custom [`HttpAuthScheme`].
+pub struct HttpAuth<T, S> {
+  inner: T,
+  _scheme: PhantomData<S>,
+}
+
+impl<T, S> HttpAuth<T, S> {
+  pub fn into_inner(self) -> T {
+    self.inner
+  }
+}
+
+impl<T, S> Deref for HttpAuth<T, S> {
+  type Target = T;
+
+  fn deref(&self) -> &Self::Target {
+    &self.inner
+  }
+}
+
+impl<T: FromRequest, S> FromRequest for HttpAuth<T, S> {
+  type Error = T::Error;
+  type Future = HttpAuthFuture<T::Future, S>;
+
+  fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+    HttpAuthFuture {
+      inner: T::from_request(req, payload),
+      _scheme: PhantomData,
+    }
+  }
+}
+
+#[pin_project]
+pub struct HttpAuthFuture<F, S> {
+  #[pin]
+  inner: F,
+  _scheme: PhantomData<S>,
+}
+
+impl<F, T, E, S> Future for HttpAuthFuture<F, S>
+where
+  F: Future<Output = Result<T, E>>,
+{
+  type Output = Result<HttpAuth<T, S>, E>;
+
+  fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    self.project().inner.poll(cx).map(|res| {
+      res.map(|inner| HttpAuth {
+        inner,
+        _scheme: PhantomData,
+      })
+    })
+  }
+}
+
+/// Extractors which can be documented as an `http` security scheme.
+pub trait HttpAuthExtractor {
+  type DefaultScheme: HttpAuthScheme;
+
+  fn scheme() -> String;
+
+  fn security_scheme<S: HttpAuthScheme>() -> SecurityScheme {
+    SecurityScheme {
+      _type: SecurityType::Http(Http {
+        scheme: Self::scheme(),
+        bearer_format: S::bearer_format().filter(|_| Self::scheme() == "bearer"),
+      }),
+      description: S::description(),
+      extensions: Default::default(),
+    }
+  }
+}
+
+impl HttpAuthExtractor for BearerAuth {
+  type DefaultScheme = DefaultBearerScheme;
+
+  fn scheme() -> String {
+    "bearer".to_string()
+  }
+}
+
+impl HttpAuthExtractor for BasicAuth {
+  type DefaultScheme = DefaultBasicScheme;
+
+  fn scheme() -> String {
+    "basic".to_string()
+  }
+}
+
+impl<T, S> ApiComponent for HttpAuth<T, S>
+where
+  T: HttpAuthExtractor,
+  S: HttpAuthScheme,
+{
+  fn child_schemas() -> Vec<(String, ReferenceOr<Schema>)> {
+    vec![]
+  }
+
+  fn schema() -> Option<(String, ReferenceOr<Schema>)> {
+    None
+  }
+
+  fn securities() -> BTreeMap<String, SecurityScheme> {
+    BTreeMap::from_iter([(S::name(), T::security_scheme::<S>())])
+  }
+
+  fn security_requirement_name() -> Option<String> {
+    Some(S::name())
+  }
+}
+
+macro_rules! impl_http_auth_component {
+  ($($ty:ty),*) => {
+    $(
+      impl ApiComponent for $ty {
+        fn child_schemas() -> Vec<(String, ReferenceOr<Schema>)> {
+          vec![]
+        }
+
+        fn schema() -> Option<(String, ReferenceOr<Schema>)> {
+          None
+        }
+
+        fn securities() -> BTreeMap<String, SecurityScheme> {
+          HttpAuth::<$ty, <$ty as HttpAuthExtractor>::DefaultScheme>::securities()
+        }
+
+        fn security_requirement_name() -> Option<String> {
+          HttpAuth::<$ty, <$ty as HttpAuthExtractor>::DefaultScheme>::security_requirement_name()
+        }
+      }
+    )*
+  };
+}
+
+impl_http_auth_component!(BearerAuth, BasicAuth);
+
+#[cfg(test)]
+mod test {
+  use crate::httpauth::{HttpAuth, HttpAuthScheme};
+  use crate::ApiComponent;
+  use actix_web_httpauth::extractors::basic::BasicAuth;
+  use actix_web_httpauth::extractors::bearer::BearerAuth;
+  use assert_json_diff::assert_json_eq;
+  use serde_json::json;
+
+  #[test]
+  fn http_auth_default_schemes() {
+    assert_eq!(
+      <BearerAuth as ApiComponent>::security_requirement_name().as_deref(),
+      Some("bearer_auth")
+    );
+    assert_eq!(
+      <Option<BasicAuth> as ApiComponent>::security_requirement_name().as_deref(),
+      Some("basic_auth")
+    );
+    assert!(!<Option<BasicAuth> as ApiComponent>::required());
+
+    let json = serde_json::to_value(<BearerAuth as ApiComponent>::securities()).expect("Unable to serialize as Json");
+    assert_json_eq!(
+      json,
+      json!({
+        "bearer_auth": {
+          "type": "http",
+          "scheme": "bearer"
+        }
+      })
+    );
+  }
+
+  #[test]
+  fn http_auth_custom_scheme() {
+    struct Jwt;
+
+    impl HttpAuthScheme for Jwt {
+      fn name() -> String {
+        "jwt".to_string()
+      }
+
+      fn bearer_format() -> Option<String> {
+        Some("JWT".to_string())
+      }
+
+      fn description() -> Option<String> {
+        Some("A signed token".to_string())
+      }
+    }
+
+    let json = serde_json::to_value(<HttpAuth<BearerAuth, Jwt> as ApiComponent>::securities())
+      .expect("Unable to serialize as Json");
+    assert_json_eq!(
+      json,
+      json!({
+        "jwt": {
+          "type": "http",
+          "scheme": "bearer",
+          "bearerFormat": "JWT",
+          "description": "A signed token"
+        }
+      })
+    );
+
+    let json = serde_json::to_value(<HttpAuth<BasicAuth, Jwt> as ApiComponent>::securities())
+      .expect("Unable to serialize as Json");
+    assert_json_eq!(
+      json,
+      json!({
+        "jwt": {
+          "type": "http",
+          "scheme": "basic",
+          "description": "A signed token"
+        }
+      })
+    );
+  }
+}
@@ -1,7 +1,64 @@
+/// Document `Result<$extractor<T>, $error>` extractors, for which failures are handled by the handler itself. The handler
+/// running without a valid body or parameters, they are documented as optional like `Option<$extractor<T>>`.
+#[cfg(feature = "actix")]
+macro_rules! impl_fallible_extractor {
+  ($extractor:ident, $error:ty) => {
+    impl<T> ApiComponent for Result<$extractor<T>, $error>
+    where
+      $extractor<T>: ApiComponent,
+    {
+      fn content_type() -> String {
+        <$extractor<T>>::content_type()
+      }
+
+      fn required() -> bool {
+        false
+      }
+
+      fn child_schemas() -> Vec<(String, ReferenceOr<Schema>)> {
+        <$extractor<T>>::child_schemas()
+      }
+
+      fn raw_schema() -> Option<ReferenceOr<Schema>> {
+        <$extractor<T>>::raw_schema()
+      }
+
+      fn schema() -> Option<(String, ReferenceOr<Schema>)> {
+        <$extractor<T>>::schema()
+      }
+
+      fn request_body() -> Option<RequestBody> {
+        <$extractor<T>>::request_body().map(|request_body| RequestBody {
+          required: Some(false),
+          ..request_body
+        })
+      }
+
+      fn parameters() -> Vec<Parameter> {
+        <$extractor<T>>::parameters()
+          .into_iter()
+          .map(|parameter| match parameter._in {
+            // path parameters are always required
+            ParameterIn::Path => parameter,
+            _ => Parameter {
+              required: Some(false),
+              ..parameter
+            },
+          })
+          .collect()
+      }
+    }
+  };
+}
+
+#[cfg(feature = "actix")]
+pub mod either;
 #[cfg(feature = "actix")]
 pub mod empty;
 #[cfg(feature = "actix")]
 pub mod form;
+#[cfg(feature = "httpauth")]
+pub mod httpauth;
 #[cfg(feature = "actix")]
 pub mod json;
 #[cfg(feature = "multipart")]
@@ -1,6 +1,9 @@
 use crate::ApiComponent;
+use actix_web::error::ParseError;
 use actix_web::web::Header;
-use apistos_models::paths::{Parameter, ParameterDefinition, ParameterIn, ParameterStyle, RequestBody};
+use apistos_models::paths::{
+  Header as HeaderDefinition, Parameter, ParameterDefinition, ParameterIn, ParameterStyle, RequestBody, Response,
+};
 use apistos_models::reference_or::ReferenceOr;
 use apistos_models::Schema;
 
@@ -15,6 +18,58 @@ pub trait ApiHeader {
   fn deprecated() -> bool {
     Default::default()
   }
+  /// Document the header once in `components/headers` and reference it from responses.
+  fn component() -> bool {
+    Default::default()
+  }
+}
+
+/// Response header documented by `T`, along with its name.
+pub fn response_header<T>() -> (String, HeaderDefinition)
+where
+  T: ApiComponent + ApiHeader,
+{
+  (
+    T::name(),
+    HeaderDefinition {
+      required: Some(<T as ApiHeader>::required()),
+      deprecated: Some(<T as ApiHeader>::deprecated()),
+      description: T::description(),
+      definition: T::schema()
+        .map(|(_, schema)| schema)
+        .or_else(T::raw_schema)
+        .map(ParameterDefinition::Schema),
+      style: Some(ParameterStyle::Simple),
+    },
+  )
+}
+
+/// Header component documented by `T`, `None` unless `T` is documented as a component.
+pub fn response_header_component<T>() -> Option<(String, ReferenceOr<HeaderDefinition>)>
+where
+  T: ApiComponent + ApiHeader,
+{
+  <T as ApiHeader>::component().then(|| {
+    let (name, header) = response_header::<T>();
+    (name, ReferenceOr::Object(header))
+  })
+}
+
+/// Document the header `T` on `response`, referencing `#/components/headers/{name}` when `T` is documented as a
+/// component. Such headers must be exposed through [`ApiComponent::header_components`].
+pub fn insert_response_header<T>(response: &mut Response)
+where
+  T: ApiComponent + ApiHeader,
+{
+  let (name, header) = response_header::<T>();
+  let header = if <T as ApiHeader>::component() {
+    ReferenceOr::Reference {
+      _ref: format!("#/components/headers/{name}"),
+    }
+  } else {
+    ReferenceOr::Object(header)
+  };
+  response.headers.insert(name, header);
 }
 
 impl<T> ApiComponent for Header<T>
@@ -54,6 +109,8 @@ where
   }
 }
 
+impl_fallible_extractor!(Header, ParseError);
+
 #[cfg(feature = "garde")]
 impl<T> ApiComponent for garde_actix_web::web::Header<T>
 where
@@ -1,6 +1,10 @@
+use crate::extractor_errors::path_error_responses;
+#[cfg(feature = "garde")]
+use crate::extractor_errors::validated;
 use crate::ApiComponent;
+use actix_web::error::PathError;
 use actix_web::web::Path;
-use apistos_models::paths::{Parameter, ParameterDefinition, ParameterIn, RequestBody};
+use apistos_models::paths::{Parameter, ParameterDefinition, ParameterIn, RequestBody, Response};
 use apistos_models::reference_or::ReferenceOr;
 use apistos_models::ObjectValidation;
 use apistos_models::Schema;
@@ -40,8 +44,14 @@ where
       vec![]
     }
   }
+
+  fn extractor_error_responses() -> Vec<(String, Response)> {
+    path_error_responses()
+  }
 }
 
+impl_fallible_extractor!(Path, PathError);
+
 #[cfg(feature = "garde")]
 impl<T> ApiComponent for garde_actix_web::web::Path<T>
 where
@@ -76,6 +86,10 @@ where
       vec![]
     }
   }
+
+  fn extractor_error_responses() -> Vec<(String, Response)> {
+    validated(path_error_responses())
+  }
 }
 
 macro_rules! impl_path_tuple ({ $($ty:ident),+ } => {
@@ -115,6 +129,10 @@ macro_rules! impl_path_tuple ({ $($ty:ident),+ } => {
       )+
       parameters
     }
+
+    fn extractor_error_responses() -> Vec<(String, Response)> {
+      path_error_responses()
+    }
   }
 
   #[cfg(feature = "garde")]
@@ -154,6 +172,10 @@ macro_rules! impl_path_tuple ({ $($ty:ident),+ } => {
       )+
       parameters
     }
+
+    fn extractor_error_responses() -> Vec<(String, Response)> {
+      validated(path_error_responses())
+    }
   }
 });
 
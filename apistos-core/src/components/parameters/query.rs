@@ -1,8 +1,14 @@
+#[cfg(any(feature = "query", feature = "qs_query"))]
+use crate::extractor_errors::query_error_responses;
+#[cfg(all(any(feature = "query", feature = "qs_query"), feature = "garde"))]
+use crate::extractor_errors::validated;
 use crate::ApiComponent;
 #[cfg(feature = "query")]
+use actix_web::error::QueryPayloadError;
+#[cfg(feature = "query")]
 use actix_web::web::Query;
 use apistos_models::paths::ParameterStyle;
-use apistos_models::paths::{Parameter, ParameterDefinition, ParameterIn, RequestBody};
+use apistos_models::paths::{Parameter, ParameterDefinition, ParameterIn, RequestBody, Response};
 use apistos_models::reference_or::ReferenceOr;
 use apistos_models::Schema;
 use apistos_models::{ObjectValidation, SchemaObject};
@@ -39,6 +45,10 @@ where
     let schema = T::schema().map(|(_, sch)| sch).or_else(Self::raw_schema);
     parameters_from_schema(schema, None, &None)
   }
+
+  fn extractor_error_responses() -> Vec<(String, Response)> {
+    query_error_responses()
+  }
 }
 
 #[cfg(feature = "query")]
@@ -70,8 +80,15 @@ where
     let schema = V::schema().map(|(_, sch)| sch).or_else(Self::raw_schema);
     parameters_from_hashmap(schema, None)
   }
+
+  fn extractor_error_responses() -> Vec<(String, Response)> {
+    query_error_responses()
+  }
 }
 
+#[cfg(feature = "query")]
+impl_fallible_extractor!(Query, QueryPayloadError);
+
 #[cfg(feature = "qs_query")]
 impl<T> ApiComponent for QsQuery<T>
 where
@@ -101,6 +118,10 @@ where
     let schema = T::schema().map(|(_, sch)| sch).or_else(Self::raw_schema);
     parameters_from_schema(schema, None, &None)
   }
+
+  fn extractor_error_responses() -> Vec<(String, Response)> {
+    query_error_responses()
+  }
 }
 
 #[cfg(feature = "qs_query")]
@@ -132,6 +153,10 @@ where
     let schema = V::schema().map(|(_, sch)| sch).or_else(Self::raw_schema);
     parameters_from_hashmap(schema, Some(ParameterStyle::DeepObject))
   }
+
+  fn extractor_error_responses() -> Vec<(String, Response)> {
+    query_error_responses()
+  }
 }
 
 #[cfg(all(feature = "query", feature = "garde"))]
@@ -163,6 +188,10 @@ where
     let schema = T::schema().map(|(_, sch)| sch).or_else(Self::raw_schema);
     parameters_from_schema(schema, None, &None)
   }
+
+  fn extractor_error_responses() -> Vec<(String, Response)> {
+    validated(query_error_responses())
+  }
 }
 
 #[cfg(all(feature = "query", feature = "garde"))]
@@ -194,6 +223,10 @@ where
     let schema = V::schema().map(|(_, sch)| sch).or_else(Self::raw_schema);
     parameters_from_hashmap(schema, None)
   }
+
+  fn extractor_error_responses() -> Vec<(String, Response)> {
+    validated(query_error_responses())
+  }
 }
 
 #[cfg(all(feature = "qs_query", feature = "garde"))]
@@ -225,6 +258,10 @@ where
     let schema = T::schema().map(|(_, sch)| sch).or_else(Self::raw_schema);
     parameters_from_schema(schema, None, &None)
   }
+
+  fn extractor_error_responses() -> Vec<(String, Response)> {
+    validated(query_error_responses())
+  }
 }
 
 #[cfg(all(feature = "qs_query", feature = "garde"))]
@@ -256,6 +293,10 @@ where
     let schema = V::schema().map(|(_, sch)| sch).or_else(Self::raw_schema);
     parameters_from_hashmap(schema, Some(ParameterStyle::DeepObject))
   }
+
+  fn extractor_error_responses() -> Vec<(String, Response)> {
+    validated(query_error_responses())
+  }
 }
 
 fn parameters_from_schema(
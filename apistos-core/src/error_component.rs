@@ -1,4 +1,4 @@
-use apistos_models::paths::Response;
+use apistos_models::paths::{Header, Response};
 use apistos_models::reference_or::ReferenceOr;
 use apistos_models::Schema;
 use std::collections::BTreeMap;
@@ -6,6 +6,11 @@ use std::collections::BTreeMap;
 pub trait ApiErrorComponent {
   fn schemas_by_status_code() -> BTreeMap<String, (String, ReferenceOr<Schema>)>;
   fn error_responses() -> Vec<(String, Response)>;
+
+  /// Response headers documented as components, referenced from the error responses.
+  fn header_components() -> Vec<(String, ReferenceOr<Header>)> {
+    vec![]
+  }
 }
 
 #[cfg(feature = "actix")]
@@ -0,0 +1,42 @@
+use apistos_models::paths::Response;
+
+/// Response answered by an extractor rejecting a request.
+pub fn extractor_error_response(status: u16, description: &str) -> (String, Response) {
+  let response = Response {
+    description: description.to_owned(),
+    ..Default::default()
+  };
+  (status.to_string(), response)
+}
+
+/// Responses answered when a json or form payload can't be read.
+#[cfg(feature = "actix")]
+pub(crate) fn json_payload_error_responses() -> Vec<(String, Response)> {
+  vec![
+    extractor_error_response(400, "Invalid payload"),
+    extractor_error_response(413, "Payload too large"),
+    extractor_error_response(415, "Unsupported media type"),
+  ]
+}
+
+/// Responses answered when path parameters can't be read.
+#[cfg(feature = "actix")]
+pub(crate) fn path_error_responses() -> Vec<(String, Response)> {
+  vec![extractor_error_response(404, "Not found")]
+}
+
+/// Responses answered when query parameters can't be read.
+#[cfg(any(feature = "query", feature = "qs_query"))]
+pub(crate) fn query_error_responses() -> Vec<(String, Response)> {
+  vec![extractor_error_response(400, "Invalid query parameters")]
+}
+
+/// Add the response answered when an extracted value doesn't pass its `garde` validation.
+#[cfg(feature = "garde")]
+pub(crate) fn validated(mut responses: Vec<(String, Response)>) -> Vec<(String, Response)> {
+  match responses.iter_mut().find(|(status, _)| status == "400") {
+    Some((_, response)) => response.description = format!("{} or validation failed", response.description),
+    None => responses.push(extractor_error_response(400, "Validation failed")),
+  }
+  responses
+}
@@ -1,16 +1,32 @@
 use apistos_models::InstanceType;
 
 mod api_component;
+mod api_scopes;
+#[cfg(feature = "actix")]
+mod api_security;
+mod api_tags;
 mod components;
 mod error_component;
+mod extractor_errors;
+mod media_types;
 mod path_item_definition;
+mod request_body;
+mod responses;
 #[cfg(feature = "actix")]
 mod wrappers;
 
-pub use api_component::ApiComponent;
+pub use api_component::{success_responses, ApiComponent};
+pub use api_scopes::ApiScopes;
+#[cfg(feature = "actix")]
+pub use api_security::{extract_credential, validate_security, ApiSecurityFuture, ApiSecurityValidator};
+pub use api_tags::ApiTags;
 pub use components::*;
 pub use error_component::ApiErrorComponent;
+pub use extractor_errors::extractor_error_response;
+pub use media_types::override_media_types;
 pub use path_item_definition::PathItemDefinition;
+pub use request_body::{document_request_bodies, merge_request_bodies};
+pub use responses::insert_response;
 #[cfg(feature = "actix")]
 pub use wrappers::{ResponderWrapper, ResponseWrapper};
 
@@ -0,0 +1,25 @@
+use apistos_models::paths::MediaType;
+use apistos_models::reference_or::ReferenceOr;
+use apistos_models::Schema;
+use std::collections::BTreeMap;
+
+/// Document `content` using the given media types, as declared with `consumes(...)` or `produces(...)`.
+///
+/// Each media type keeps the documentation already associated to it, falling back to the first documented media type,
+/// unless its schema is overridden.
+pub fn override_media_types(
+  content: &BTreeMap<String, MediaType>,
+  media_types: Vec<(String, Option<ReferenceOr<Schema>>)>,
+) -> BTreeMap<String, MediaType> {
+  let default = content.values().next().cloned().unwrap_or_default();
+  media_types
+    .into_iter()
+    .map(|(content_type, schema)| {
+      let mut media_type = content.get(&content_type).cloned().unwrap_or_else(|| default.clone());
+      if let Some(schema) = schema {
+        media_type.schema = Some(schema);
+      }
+      (content_type, media_type)
+    })
+    .collect()
+}
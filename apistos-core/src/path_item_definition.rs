@@ -6,6 +6,28 @@ pub trait PathItemDefinition {
     true
   }
 
+  /// Name of the handler documented by this definition, used to generate operation ids.
+  fn handler_name() -> Option<&'static str> {
+    None
+  }
+
+  /// Whether the operation is explicitly documented as not requiring any security, overriding inherited securities.
+  fn security_disabled() -> bool {
+    false
+  }
+
+  /// Number of arguments documented as request body which are left out of the operation, actix only reading the payload
+  /// once.
+  fn ignored_request_bodies() -> usize {
+    0
+  }
+
+  /// Status codes of the responses documented from the failures of the handler arguments extractors, i.e. not
+  /// documented by the handler itself.
+  fn extractor_error_statuses() -> Vec<String> {
+    vec![]
+  }
+
   fn operation() -> Operation {
     Default::default()
   }
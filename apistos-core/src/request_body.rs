@@ -0,0 +1,152 @@
+use apistos_models::paths::{MediaType, Operation, RequestBody};
+use apistos_models::reference_or::ReferenceOr;
+use apistos_models::{Schema, SchemaObject, SubschemaValidation};
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+
+/// Document the request body of an operation from the bodies documented by its arguments.
+///
+/// Only the first body is documented as actix can't read the payload twice, the other ones are reported through
+/// [`PathItemDefinition::ignored_request_bodies`](crate::PathItemDefinition::ignored_request_bodies).
+pub fn document_request_bodies(operation: &mut Operation, request_bodies: Vec<RequestBody>) {
+  operation.request_body = request_bodies.into_iter().next().map(ReferenceOr::Object);
+}
+
+/// Merge two alternative request bodies, as accepted by `actix_web::Either`.
+///
+/// Each media type is documented once, using a `oneOf` when both bodies document different schemas for it.
+pub fn merge_request_bodies(left: RequestBody, right: RequestBody) -> RequestBody {
+  let content = merge_content(left.content, right.content);
+  let mut extensions = left.extensions;
+  extensions.extend(right.extensions);
+  RequestBody {
+    description: left.description.or(right.description),
+    content,
+    required: Some(left.required.unwrap_or_default() && right.required.unwrap_or_default()),
+    extensions,
+  }
+}
+
+/// Merge the content of two alternatives, using a `oneOf` for media types documenting different schemas.
+pub(crate) fn merge_content(
+  mut content: BTreeMap<String, MediaType>,
+  other: BTreeMap<String, MediaType>,
+) -> BTreeMap<String, MediaType> {
+  for (content_type, media_type) in other {
+    match content.entry(content_type) {
+      Entry::Vacant(entry) => {
+        entry.insert(media_type);
+      }
+      Entry::Occupied(mut entry) => {
+        let existing = entry.get_mut();
+        existing.schema = merge_schemas(existing.schema.take(), media_type.schema);
+      }
+    }
+  }
+  content
+}
+
+fn merge_schemas(left: Option<ReferenceOr<Schema>>, right: Option<ReferenceOr<Schema>>) -> Option<ReferenceOr<Schema>> {
+  let (left, right) = match (left, right) {
+    (Some(left), Some(right)) => (left, right),
+    (left, right) => return left.or(right),
+  };
+
+  let mut one_of: Vec<Schema> = vec![];
+  for schema in one_of_schemas(left.clone()).into_iter().chain(one_of_schemas(right)) {
+    if !one_of.contains(&schema) {
+      one_of.push(schema);
+    }
+  }
+  if one_of.len() == 1 {
+    return Some(left);
+  }
+  Some(ReferenceOr::Object(Schema::Object(SchemaObject {
+    subschemas: Some(Box::new(SubschemaValidation {
+      one_of: Some(one_of),
+      ..Default::default()
+    })),
+    ..Default::default()
+  })))
+}
+
+/// Alternatives documented by a schema, flattening `oneOf` produced by nested merges.
+fn one_of_schemas(schema: ReferenceOr<Schema>) -> Vec<Schema> {
+  match schema {
+    ReferenceOr::Reference { _ref } => vec![Schema::new_ref(_ref)],
+    ReferenceOr::Object(Schema::Object(schema)) if is_one_of_only(&schema) => schema
+      .subschemas
+      .and_then(|subschemas| subschemas.one_of)
+      .unwrap_or_default(),
+    ReferenceOr::Object(schema) => vec![schema],
+  }
+}
+
+fn is_one_of_only(schema: &SchemaObject) -> bool {
+  let one_of = schema
+    .subschemas
+    .as_ref()
+    .and_then(|subschemas| subschemas.one_of.clone());
+  one_of.is_some()
+    && *schema
+      == SchemaObject {
+        subschemas: Some(Box::new(SubschemaValidation {
+          one_of,
+          ..Default::default()
+        })),
+        ..Default::default()
+      }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::request_body::merge_request_bodies;
+  use apistos_models::paths::{MediaType, RequestBody};
+  use apistos_models::reference_or::ReferenceOr;
+  use assert_json_diff::assert_json_eq;
+  use serde_json::json;
+  use std::collections::BTreeMap;
+
+  fn request_body(schema: &str, required: bool) -> RequestBody {
+    RequestBody {
+      content: BTreeMap::from_iter([(
+        "application/json".to_string(),
+        MediaType {
+          schema: Some(ReferenceOr::Reference {
+            _ref: format!("#/components/schemas/{schema}"),
+          }),
+          ..Default::default()
+        },
+      )]),
+      required: Some(required),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn merge_request_bodies_one_of() {
+    let merged = merge_request_bodies(
+      merge_request_bodies(request_body("Cat", true), request_body("Dog", true)),
+      merge_request_bodies(request_body("Dog", false), request_body("Bird", true)),
+    );
+
+    let json = serde_json::to_value(merged).expect("Unable to serialize as Json");
+    assert_json_eq!(
+      json,
+      json!({
+        "content": {
+          "application/json": {
+            "schema": {
+              "oneOf": [
+                { "$ref": "#/components/schemas/Cat" },
+                { "$ref": "#/components/schemas/Dog" },
+                { "$ref": "#/components/schemas/Bird" }
+              ]
+            }
+          }
+        },
+        "required": false
+      })
+    );
+  }
+}
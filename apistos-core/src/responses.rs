@@ -0,0 +1,27 @@
+use crate::request_body::merge_content;
+use apistos_models::paths::Response;
+use apistos_models::reference_or::ReferenceOr;
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+
+/// Document `response` for `status`, responses already documented for this status being merged using a `oneOf`.
+pub fn insert_response(
+  responses: &mut BTreeMap<String, ReferenceOr<Response>>,
+  status: String,
+  response: ReferenceOr<Response>,
+) {
+  match responses.entry(status) {
+    Entry::Vacant(entry) => {
+      entry.insert(response);
+    }
+    Entry::Occupied(mut entry) => {
+      if let (ReferenceOr::Object(existing), ReferenceOr::Object(response)) = (entry.get_mut(), response) {
+        existing.content = merge_content(std::mem::take(&mut existing.content), response.content);
+        existing.headers.extend(response.headers);
+        if existing.description.is_empty() {
+          existing.description = response.description;
+        }
+      }
+    }
+  }
+}
@@ -47,6 +47,22 @@ where
     P::is_visible()
   }
 
+  fn handler_name() -> Option<&'static str> {
+    P::handler_name()
+  }
+
+  fn security_disabled() -> bool {
+    P::security_disabled()
+  }
+
+  fn ignored_request_bodies() -> usize {
+    P::ignored_request_bodies()
+  }
+
+  fn extractor_error_statuses() -> Vec<String> {
+    P::extractor_error_statuses()
+  }
+
   fn operation() -> Operation {
     P::operation()
   }
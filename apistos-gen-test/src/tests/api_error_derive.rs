@@ -1,6 +1,8 @@
 use apistos_core::ApiErrorComponent;
-use apistos_gen::ApiErrorComponent;
+use apistos_gen::{ApiErrorComponent, ApiHeader};
+use assert_json_diff::assert_json_eq;
 use schemars::JsonSchema;
+use serde_json::json;
 use std::collections::{BTreeMap, HashSet};
 
 #[test]
@@ -97,3 +99,84 @@ fn api_component_with_schema() {
     .unwrap_or_default()
     .is_empty());
 }
+
+#[test]
+#[allow(dead_code)]
+fn api_error_with_headers() {
+  #[derive(JsonSchema, ApiHeader)]
+  #[openapi_header(name = "Retry-After", description = "Seconds to wait before retrying", component)]
+  struct RetryAfter(u32);
+
+  #[derive(JsonSchema, ApiHeader)]
+  #[openapi_header(name = "X-Rate-Limit-Remaining")]
+  struct RateLimitRemaining(u32);
+
+  #[derive(ApiErrorComponent)]
+  #[openapi_error(
+    status(code = 404),
+    status(code = 429, header = RetryAfter, header = RateLimitRemaining),
+    status(code = 503, description = "Under maintenance", header = RetryAfter)
+  )]
+  enum ErrorResponse {
+    NotFound(String),
+    TooManyRequests(u32),
+    ServiceUnavailable(u32),
+  }
+
+  let error_responses = BTreeMap::from_iter(<ErrorResponse as ApiErrorComponent>::error_responses());
+  assert!(error_responses
+    .get("404")
+    .map(|r| r.headers.is_empty())
+    .unwrap_or_default());
+
+  let json = serde_json::to_value(error_responses.get("429")).expect("Unable to serialize as Json");
+  assert_json_eq!(
+    json,
+    json!({
+      "description": "Too Many Requests",
+      "headers": {
+        "Retry-After": {
+          "$ref": "#/components/headers/Retry-After"
+        },
+        "X-Rate-Limit-Remaining": {
+          "deprecated": false,
+          "required": false,
+          "schema": {
+            "format": "uint32",
+            "minimum": 0.0,
+            "title": "RateLimitRemaining",
+            "type": "integer"
+          },
+          "style": "simple"
+        }
+      }
+    })
+  );
+
+  let header_components = BTreeMap::from_iter(<ErrorResponse as ApiErrorComponent>::header_components());
+  let json = serde_json::to_value(header_components).expect("Unable to serialize as Json");
+  assert_json_eq!(
+    json,
+    json!({
+      "Retry-After": {
+        "deprecated": false,
+        "description": "Seconds to wait before retrying",
+        "required": false,
+        "schema": {
+          "format": "uint32",
+          "minimum": 0.0,
+          "title": "RetryAfter",
+          "type": "integer"
+        },
+        "style": "simple"
+      }
+    })
+  );
+
+  let service_unavailable = error_responses.get("503").cloned().unwrap_or_default();
+  assert_eq!(service_unavailable.description, "Under maintenance");
+  assert_eq!(
+    service_unavailable.headers.keys().collect::<Vec<&String>>(),
+    vec!["Retry-After"]
+  );
+}
@@ -1,8 +1,8 @@
 use actix_multipart::form::MultipartForm;
 use actix_web::http::header::ContentType;
-use actix_web::web::Json;
+use actix_web::web::{Json, Path};
 use actix_web::HttpResponse;
-use apistos::actix::{AcceptedJson, CreatedJson, NoContent};
+use apistos::actix::{AcceptedJson, CreatedJson, NoContent, StatusJson};
 use apistos_core::PathItemDefinition;
 use apistos_gen::api_operation;
 use assert_json_diff::assert_json_eq;
@@ -103,6 +103,32 @@ mod test_models {
       todo!()
     }
   }
+
+  #[derive(ApiSecurity)]
+  #[openapi_security(scheme(security_type(http(scheme = "bearer", bearer_format = "JWT"))))]
+  pub(crate) struct Bearer;
+
+  impl FromRequest for Bearer {
+    type Error = Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(_req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+      todo!()
+    }
+  }
+
+  #[derive(ApiSecurity)]
+  #[openapi_security(all_of(ApiKey, Bearer))]
+  pub(crate) struct ApiKeyAndBearer;
+
+  impl FromRequest for ApiKeyAndBearer {
+    type Error = Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(_req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+      todo!()
+    }
+  }
 }
 
 #[test]
@@ -187,8 +213,17 @@ fn api_operation() {
           },
           "description": ""
         },
+        "400": {
+          "description": "Invalid payload"
+        },
         "405": {
           "description": "Invalid input"
+        },
+        "413": {
+          "description": "Payload too large"
+        },
+        "415": {
+          "description": "Unsupported media type"
         }
       },
       "summary": "Add a new pet to the store",
@@ -258,8 +293,17 @@ fn api_operation_no_content() {
         "204": {
           "description": ""
         },
+        "400": {
+          "description": "Invalid payload"
+        },
         "405": {
           "description": "Invalid input"
+        },
+        "413": {
+          "description": "Payload too large"
+        },
+        "415": {
+          "description": "Unsupported media type"
         }
       },
       "summary": "Add a new pet to the store",
@@ -280,7 +324,7 @@ fn api_operation_created_json() {
   pub(crate) async fn test(
     _body: Json<test_models::Test>,
   ) -> Result<CreatedJson<test_models::TestResult>, test_models::ErrorResponse> {
-    Ok(CreatedJson(test_models::TestResult { id: 1 }))
+    Ok(StatusJson(test_models::TestResult { id: 1 }))
   }
 
   let components = __openapi_test::components();
@@ -352,8 +396,17 @@ fn api_operation_created_json() {
           },
           "description": ""
         },
+        "400": {
+          "description": "Invalid payload"
+        },
         "405": {
           "description": "Invalid input"
+        },
+        "413": {
+          "description": "Payload too large"
+        },
+        "415": {
+          "description": "Unsupported media type"
         }
       },
       "summary": "Add a new pet to the store",
@@ -374,7 +427,7 @@ fn api_operation_accepted_json() {
   pub(crate) async fn test(
     _body: Json<test_models::Test>,
   ) -> Result<AcceptedJson<test_models::TestResult>, test_models::ErrorResponse> {
-    Ok(AcceptedJson(test_models::TestResult { id: 0 }))
+    Ok(StatusJson(test_models::TestResult { id: 0 }))
   }
 
   let components = __openapi_test::components();
@@ -446,8 +499,17 @@ fn api_operation_accepted_json() {
           },
           "description": ""
         },
+        "400": {
+          "description": "Invalid payload"
+        },
         "405": {
           "description": "Invalid input"
+        },
+        "413": {
+          "description": "Payload too large"
+        },
+        "415": {
+          "description": "Unsupported media type"
         }
       },
       "summary": "Add a new pet to the store",
@@ -468,7 +530,7 @@ fn api_operation_deprecated() {
   pub(crate) async fn test(
     _body: Json<test_models::Test>,
   ) -> Result<CreatedJson<test_models::TestResult>, test_models::ErrorResponse> {
-    Ok(CreatedJson(test_models::TestResult { id: 4 }))
+    Ok(StatusJson(test_models::TestResult { id: 4 }))
   }
 
   let components = __openapi_test::components();
@@ -539,8 +601,17 @@ fn api_operation_deprecated() {
           },
           "description": ""
         },
+        "400": {
+          "description": "Invalid payload"
+        },
         "405": {
           "description": "Invalid input"
+        },
+        "413": {
+          "description": "Payload too large"
+        },
+        "415": {
+          "description": "Unsupported media type"
         }
       },
       "summary": "Add a new pet to the store",
@@ -558,7 +629,7 @@ fn api_operation_deprecated() {
   pub(crate) async fn test2(
     _body: Json<test_models::Test>,
   ) -> Result<CreatedJson<test_models::TestResult>, test_models::ErrorResponse> {
-    Ok(CreatedJson(test_models::TestResult { id: 2 }))
+    Ok(StatusJson(test_models::TestResult { id: 2 }))
   }
 
   let components = __openapi_test2::components();
@@ -630,8 +701,17 @@ fn api_operation_deprecated() {
           },
           "description": ""
         },
+        "400": {
+          "description": "Invalid payload"
+        },
         "405": {
           "description": "Invalid input"
+        },
+        "413": {
+          "description": "Payload too large"
+        },
+        "415": {
+          "description": "Unsupported media type"
         }
       },
       "summary": "Add a new pet to the store",
@@ -652,7 +732,7 @@ fn api_operation_skip() {
   pub(crate) async fn test(
     _body: Json<test_models::Test>,
   ) -> Result<CreatedJson<test_models::TestResult>, test_models::ErrorResponse> {
-    Ok(CreatedJson(test_models::TestResult { id: 6 }))
+    Ok(StatusJson(test_models::TestResult { id: 6 }))
   }
 
   let components = __openapi_test::components();
@@ -679,7 +759,7 @@ fn api_operation_error() {
   pub(crate) async fn test(
     _body: Json<test_models::Test>,
   ) -> Result<CreatedJson<test_models::TestResult>, test_models::MultipleErrorResponse> {
-    Ok(CreatedJson(test_models::TestResult { id: 1 }))
+    Ok(StatusJson(test_models::TestResult { id: 1 }))
   }
 
   let components = __openapi_test::components();
@@ -776,7 +856,7 @@ fn api_operation_security() {
     _body: Json<test_models::Test>,
     _key: test_models::ApiKey,
   ) -> Result<CreatedJson<test_models::TestResult>, test_models::MultipleErrorResponse> {
-    Ok(CreatedJson(test_models::TestResult { id: 0 }))
+    Ok(StatusJson(test_models::TestResult { id: 0 }))
   }
 
   let components = __openapi_test::components();
@@ -862,6 +942,9 @@ fn api_operation_security() {
           },
           "description": ""
         },
+        "400": {
+          "description": "Invalid payload"
+        },
         "401": {
           "description": "Unauthorized"
         },
@@ -873,6 +956,12 @@ fn api_operation_security() {
         },
         "405": {
           "description": "Method Not Allowed"
+        },
+        "413": {
+          "description": "Payload too large"
+        },
+        "415": {
+          "description": "Unsupported media type"
         }
       },
       "security": [
@@ -887,6 +976,45 @@ fn api_operation_security() {
   );
 }
 
+#[test]
+#[allow(dead_code)]
+fn api_operation_combined_security() {
+  #[api_operation(security_scope(name = "api_key", scope = "read:pets"))]
+  pub(crate) async fn test(
+    _body: Json<test_models::Test>,
+    _key_and_bearer: test_models::ApiKeyAndBearer,
+    _bearer: test_models::Bearer,
+  ) -> Result<CreatedJson<test_models::TestResult>, test_models::MultipleErrorResponse> {
+    Ok(StatusJson(test_models::TestResult { id: 0 }))
+  }
+
+  let components = __openapi_test::components();
+  let security_schemes = components
+    .iter()
+    .flat_map(|c| c.security_schemes.keys().cloned())
+    .collect::<Vec<String>>();
+  assert_eq!(security_schemes, vec!["api_key".to_string(), "bearer".to_string()]);
+
+  let operation = __openapi_test::operation();
+  let security = serde_json::to_value(operation.security).expect("Unable to serialize as Json");
+
+  // both securities of a single argument are required while each argument is an alternative
+  assert_json_eq!(
+    security,
+    json!([
+      {
+        "api_key": [
+          "read:pets"
+        ],
+        "bearer": []
+      },
+      {
+        "bearer": []
+      }
+    ])
+  );
+}
+
 #[test]
 #[allow(dead_code)]
 fn api_operation_multipart() {
@@ -1133,8 +1261,17 @@ fn api_operation_root_vec() {
           },
           "description": ""
         },
+        "400": {
+          "description": "Invalid payload"
+        },
         "405": {
           "description": "Invalid input"
+        },
+        "413": {
+          "description": "Payload too large"
+        },
+        "415": {
+          "description": "Unsupported media type"
         }
       },
       "summary": "Add a new pet to the store",
@@ -1144,3 +1281,516 @@ fn api_operation_root_vec() {
     })
   );
 }
+
+#[test]
+#[allow(dead_code)]
+fn api_operation_extractor_errors() {
+  #[api_operation(tag = "pet", error_code = "405", error_code = "415")]
+  pub(crate) async fn test(
+    _id: Path<u32>,
+    _body: Json<test_models::Test>,
+  ) -> Result<Json<test_models::TestResult>, test_models::ErrorResponse> {
+    Ok(Json(test_models::TestResult { id: 1 }))
+  }
+
+  let operation = __openapi_test::operation();
+  // extractors failures are filtered the same way handler errors are
+  assert_eq!(
+    operation.responses.responses.keys().cloned().collect::<Vec<String>>(),
+    vec!["200", "405", "415"]
+  );
+  assert_eq!(__openapi_test::extractor_error_statuses(), vec!["415"]);
+  let responses = serde_json::to_value(operation.responses).expect("Unable to serialize as Json");
+  assert_json_eq!(
+    responses.get("415").cloned().unwrap_or_default(),
+    json!({
+      "description": "Unsupported media type"
+    })
+  );
+}
+
+#[test]
+#[allow(dead_code)]
+fn api_operation_optional_extractors() {
+  use actix_web::web::Query;
+  use apistos_gen::ApiComponent;
+  use schemars::JsonSchema;
+  use serde::Deserialize;
+
+  #[derive(Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+  pub(crate) struct PetQuery {
+    name: String,
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn test(
+    _query: Option<Query<PetQuery>>,
+    _body: Option<Json<test_models::Test>>,
+    _key: test_models::ApiKey,
+  ) -> Result<Json<test_models::TestResult>, test_models::ErrorResponse> {
+    Ok(Json(test_models::TestResult { id: 1 }))
+  }
+
+  let operation = __openapi_test::operation();
+  let operation = serde_json::to_value(operation).expect("Unable to serialize as Json");
+
+  assert_json_eq!(
+    operation.get("parameters").cloned().unwrap_or_default(),
+    json!([
+      {
+        "in": "query",
+        "name": "name",
+        "required": false,
+        "schema": {
+          "type": "string"
+        }
+      }
+    ])
+  );
+  assert_json_eq!(
+    operation.get("requestBody").cloned().unwrap_or_default(),
+    json!({
+      "content": {
+        "application/json": {
+          "schema": {
+            "$ref": "#/components/schemas/Test"
+          }
+        }
+      },
+      "required": false
+    })
+  );
+  // optional extractors never fail
+  assert_json_eq!(
+    operation.get("responses").cloned().unwrap_or_default(),
+    json!({
+      "200": {
+        "content": {
+          "application/json": {
+            "schema": {
+              "$ref": "#/components/schemas/TestResult"
+            }
+          }
+        },
+        "description": ""
+      },
+      "405": {
+        "description": "Invalid input"
+      }
+    })
+  );
+  // only optional securities make the operation public
+  assert_json_eq!(
+    operation.get("security").cloned().unwrap_or_default(),
+    json!([
+      {
+        "api_key": []
+      }
+    ])
+  );
+}
+
+#[test]
+#[allow(dead_code)]
+fn api_operation_fallible_extractors() {
+  use actix_web::error::{JsonPayloadError, PathError, QueryPayloadError};
+  use actix_web::web::Query;
+  use apistos_gen::ApiComponent;
+  use schemars::JsonSchema;
+  use serde::Deserialize;
+
+  #[derive(Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+  pub(crate) struct PetQuery {
+    name: String,
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn test(
+    _id: Result<Path<u32>, PathError>,
+    _query: Result<Query<PetQuery>, QueryPayloadError>,
+    _body: Result<Json<test_models::Test>, JsonPayloadError>,
+  ) -> Result<Json<test_models::TestResult>, test_models::ErrorResponse> {
+    Ok(Json(test_models::TestResult { id: 1 }))
+  }
+
+  let operation = __openapi_test::operation();
+  let operation = serde_json::to_value(operation).expect("Unable to serialize as Json");
+
+  assert_json_eq!(
+    operation.get("parameters").cloned().unwrap_or_default(),
+    json!([
+      {
+        "in": "path",
+        "name": "",
+        "required": true,
+        "schema": {
+          "format": "uint32",
+          "minimum": 0.0,
+          "title": "uint32",
+          "type": "integer"
+        }
+      },
+      {
+        "in": "query",
+        "name": "name",
+        "required": false,
+        "schema": {
+          "type": "string"
+        }
+      }
+    ])
+  );
+  // the handler runs without a valid body
+  assert_json_eq!(
+    operation.get("requestBody").cloned().unwrap_or_default(),
+    json!({
+      "content": {
+        "application/json": {
+          "schema": {
+            "$ref": "#/components/schemas/Test"
+          }
+        }
+      },
+      "required": false
+    })
+  );
+  // extraction failures are handled by the handler
+  assert_eq!(
+    operation
+      .get("responses")
+      .and_then(|responses| responses.as_object())
+      .map(|responses| responses.keys().cloned().collect::<Vec<String>>())
+      .unwrap_or_default(),
+    vec!["200", "405"]
+  );
+}
+
+#[test]
+#[allow(dead_code)]
+fn api_operation_either_request_body() {
+  use actix_web::web::Form;
+  use actix_web::Either;
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn test(
+    _body: Either<Json<test_models::Test>, Form<test_models::Test>>,
+  ) -> Result<Json<test_models::TestResult>, test_models::ErrorResponse> {
+    Ok(Json(test_models::TestResult { id: 1 }))
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn test_nested(
+    _body: Either<Json<test_models::Test>, Either<Json<test_models::TestResult>, Form<test_models::Test>>>,
+  ) -> Result<Json<test_models::TestResult>, test_models::ErrorResponse> {
+    Ok(Json(test_models::TestResult { id: 1 }))
+  }
+
+  let components = __openapi_test::components();
+  let operation = __openapi_test::operation();
+  let operation = serde_json::to_value(operation).expect("Unable to serialize as Json");
+  let schemas = components
+    .first()
+    .map(|components| components.schemas.keys().cloned().collect::<Vec<String>>())
+    .unwrap_or_default();
+
+  assert_eq!(schemas, vec!["Test", "TestResult"]);
+  assert_json_eq!(
+    operation.get("requestBody").cloned().unwrap_or_default(),
+    json!({
+      "content": {
+        "application/json": {
+          "schema": {
+            "$ref": "#/components/schemas/Test"
+          }
+        },
+        "application/x-www-form-urlencoded": {
+          "schema": {
+            "$ref": "#/components/schemas/Test"
+          }
+        }
+      },
+      "required": true
+    })
+  );
+
+  let operation = __openapi_test_nested::operation();
+  let operation = serde_json::to_value(operation).expect("Unable to serialize as Json");
+
+  assert_json_eq!(
+    operation.get("requestBody").cloned().unwrap_or_default(),
+    json!({
+      "content": {
+        "application/json": {
+          "schema": {
+            "oneOf": [
+              {
+                "$ref": "#/components/schemas/Test"
+              },
+              {
+                "$ref": "#/components/schemas/TestResult"
+              }
+            ]
+          }
+        },
+        "application/x-www-form-urlencoded": {
+          "schema": {
+            "$ref": "#/components/schemas/Test"
+          }
+        }
+      },
+      "required": true
+    })
+  );
+}
+
+#[test]
+#[allow(dead_code)]
+fn api_operation_conflicting_request_bodies() {
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn test(
+    _body: Json<test_models::Test>,
+    _other: Json<test_models::TestResult>,
+  ) -> Result<Json<test_models::TestResult>, test_models::ErrorResponse> {
+    Ok(Json(test_models::TestResult { id: 1 }))
+  }
+
+  let operation = __openapi_test::operation();
+  let operation = serde_json::to_value(operation).expect("Unable to serialize as Json");
+
+  assert_json_eq!(
+    operation.get("requestBody").cloned().unwrap_or_default(),
+    json!({
+      "content": {
+        "application/json": {
+          "schema": {
+            "$ref": "#/components/schemas/Test"
+          }
+        }
+      },
+      "required": true
+    })
+  );
+  assert_eq!(__openapi_test::ignored_request_bodies(), 1);
+  assert!(!operation.to_string().contains("x-apistos"));
+}
+
+#[test]
+#[allow(dead_code)]
+fn api_operation_multiple_consumes_produces() {
+  use apistos_gen::ApiComponent;
+  use schemars::JsonSchema;
+  use serde::Serialize;
+
+  #[derive(Serialize, Debug, Clone, JsonSchema, ApiComponent)]
+  pub(crate) struct TestPatch {
+    test: Option<String>,
+  }
+
+  #[api_operation(
+    consumes(
+      "application/json",
+      media_type(content_type = "application/merge-patch+json", schema = TestPatch)
+    ),
+    produces("application/json", "application/vnd.company.v2+json")
+  )]
+  pub(crate) async fn test(
+    _body: Json<test_models::Test>,
+  ) -> Result<Json<test_models::TestResult>, test_models::ErrorResponse> {
+    Ok(Json(test_models::TestResult { id: 1 }))
+  }
+
+  let components = __openapi_test::components();
+  let schemas = components
+    .first()
+    .map(|components| components.schemas.keys().cloned().collect::<Vec<String>>())
+    .unwrap_or_default();
+  let operation = __openapi_test::operation();
+  let operation = serde_json::to_value(operation).expect("Unable to serialize as Json");
+
+  assert_eq!(schemas, vec!["Test", "TestPatch", "TestResult"]);
+  assert_json_eq!(
+    operation.get("requestBody").cloned().unwrap_or_default(),
+    json!({
+      "content": {
+        "application/json": {
+          "schema": {
+            "$ref": "#/components/schemas/Test"
+          }
+        },
+        "application/merge-patch+json": {
+          "schema": {
+            "$ref": "#/components/schemas/TestPatch"
+          }
+        }
+      },
+      "required": true
+    })
+  );
+  assert_json_eq!(
+    operation
+      .get("responses")
+      .and_then(|responses| responses.get("200"))
+      .cloned()
+      .unwrap_or_default(),
+    json!({
+      "content": {
+        "application/json": {
+          "schema": {
+            "$ref": "#/components/schemas/TestResult"
+          }
+        },
+        "application/vnd.company.v2+json": {
+          "schema": {
+            "$ref": "#/components/schemas/TestResult"
+          }
+        }
+      },
+      "description": ""
+    })
+  );
+}
+
+#[test]
+#[allow(dead_code)]
+fn api_operation_composite_responders() {
+  use actix_web::{CustomizeResponder, Either, Responder};
+  use apistos::actix::{ApiResponder, WithStatus};
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn test(
+    _body: Json<test_models::Test>,
+  ) -> Result<Either<Json<test_models::Test>, Json<test_models::TestResult>>, test_models::ErrorResponse> {
+    Ok(Either::Right(Json(test_models::TestResult { id: 1 })))
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn test_no_content(
+    _body: Json<test_models::Test>,
+  ) -> Result<Either<WithStatus<201, Json<test_models::TestResult>>, NoContent>, test_models::ErrorResponse> {
+    Ok(Either::Left(
+      Json(test_models::TestResult { id: 1 }).with_typed_status(),
+    ))
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn test_customized(
+    _body: Json<test_models::Test>,
+  ) -> CustomizeResponder<Json<test_models::TestResult>> {
+    Json(test_models::TestResult { id: 1 }).customize()
+  }
+
+  let operation = __openapi_test::operation();
+  let operation = serde_json::to_value(operation).expect("Unable to serialize as Json");
+
+  assert_json_eq!(
+    operation
+      .get("responses")
+      .and_then(|responses| responses.get("200"))
+      .cloned()
+      .unwrap_or_default(),
+    json!({
+      "content": {
+        "application/json": {
+          "schema": {
+            "oneOf": [
+              {
+                "$ref": "#/components/schemas/Test"
+              },
+              {
+                "$ref": "#/components/schemas/TestResult"
+              }
+            ]
+          }
+        }
+      },
+      "description": ""
+    })
+  );
+
+  let operation = __openapi_test_no_content::operation();
+  let responses = operation.responses.responses.keys().cloned().collect::<Vec<String>>();
+  assert_eq!(responses, vec!["201", "204", "400", "405", "413", "415"]);
+
+  let components = __openapi_test_customized::components();
+  let schemas = components
+    .first()
+    .map(|components| components.schemas.keys().cloned().collect::<Vec<String>>())
+    .unwrap_or_default();
+  let operation = __openapi_test_customized::operation();
+  let responses = operation.responses.responses.keys().cloned().collect::<Vec<String>>();
+  assert_eq!(schemas, vec!["Test", "TestResult"]);
+  assert_eq!(responses, vec!["200", "400", "413", "415"]);
+}
+
+#[test]
+#[allow(dead_code)]
+fn api_operation_declared_responses() {
+  use apistos_gen::ApiHeader;
+  use schemars::JsonSchema;
+
+  #[derive(Debug, Clone, JsonSchema, ApiHeader)]
+  #[openapi_header(name = "Location", description = "Url of the pet", required = true)]
+  pub(crate) struct Location(String);
+
+  #[api_operation(
+    tag = "pet",
+    response(status = 200, body = test_models::TestResult, description = "The pet"),
+    response(status = 302, description = "Pet moved", headers(Location)),
+    response(status = 404)
+  )]
+  pub(crate) async fn test(_body: Json<test_models::Test>) -> HttpResponse {
+    HttpResponse::Ok().finish()
+  }
+
+  let components = __openapi_test::components();
+  let schemas = components
+    .first()
+    .map(|components| components.schemas.keys().cloned().collect::<Vec<String>>())
+    .unwrap_or_default();
+  let operation = __openapi_test::operation();
+  let operation = serde_json::to_value(operation).expect("Unable to serialize as Json");
+
+  assert_eq!(schemas, vec!["Test", "TestResult"]);
+  assert_json_eq!(
+    operation.get("responses").cloned().unwrap_or_default(),
+    json!({
+      "200": {
+        "content": {
+          "application/json": {
+            "schema": {
+              "$ref": "#/components/schemas/TestResult"
+            }
+          }
+        },
+        "description": "The pet"
+      },
+      "302": {
+        "description": "Pet moved",
+        "headers": {
+          "Location": {
+            "deprecated": false,
+            "description": "Url of the pet",
+            "required": true,
+            "schema": {
+              "title": "Location",
+              "type": "string"
+            },
+            "style": "simple"
+          }
+        }
+      },
+      "404": {
+        "description": "Not Found"
+      },
+      "400": {
+        "description": "Invalid payload"
+      },
+      "413": {
+        "description": "Payload too large"
+      },
+      "415": {
+        "description": "Unsupported media type"
+      }
+    })
+  );
+}
@@ -0,0 +1,88 @@
+use actix_web::test::TestRequest;
+use actix_web::Responder;
+use apistos_core::ApiComponent;
+use apistos_gen::{ApiComponent, ApiResponses};
+use assert_json_diff::assert_json_eq;
+use schemars::JsonSchema;
+use serde::Serialize;
+use serde_json::json;
+
+#[test]
+#[allow(dead_code)]
+fn api_responses_derive() {
+  #[derive(Serialize, JsonSchema, ApiComponent)]
+  struct Pet {
+    name: String,
+  }
+
+  #[derive(Serialize, JsonSchema, ApiComponent)]
+  struct Task {
+    id: u32,
+  }
+
+  #[derive(ApiResponses)]
+  enum PetResponse {
+    /// The pet
+    #[openapi_response(status = 200)]
+    Found(Pet),
+    #[openapi_response(status = 200)]
+    Pending(Task),
+    #[openapi_response(status = 201, description = "Pet created")]
+    Created(Pet),
+    #[openapi_response(status = 204)]
+    Deleted,
+  }
+
+  let schemas = <PetResponse as ApiComponent>::child_schemas()
+    .into_iter()
+    .map(|(name, _)| name)
+    .collect::<Vec<String>>();
+  assert_eq!(schemas, vec!["Pet", "Task", "Pet"]);
+
+  let responses = <PetResponse as ApiComponent>::responses(None).expect("missing responses");
+  let json = serde_json::to_value(responses).expect("Unable to serialize as Json");
+  assert_json_eq!(
+    json,
+    json!({
+      "200": {
+        "content": {
+          "application/json": {
+            "schema": {
+              "oneOf": [
+                {
+                  "$ref": "#/components/schemas/Pet"
+                },
+                {
+                  "$ref": "#/components/schemas/Task"
+                }
+              ]
+            }
+          }
+        },
+        "description": "The pet"
+      },
+      "201": {
+        "content": {
+          "application/json": {
+            "schema": {
+              "$ref": "#/components/schemas/Pet"
+            }
+          }
+        },
+        "description": "Pet created"
+      },
+      "204": {
+        "description": "No Content"
+      }
+    })
+  );
+
+  let req = TestRequest::default().to_http_request();
+  let response = PetResponse::Created(Pet {
+    name: "Rex".to_string(),
+  })
+  .respond_to(&req);
+  assert_eq!(response.status().as_u16(), 201);
+  let response = PetResponse::Deleted.respond_to(&req);
+  assert_eq!(response.status().as_u16(), 204);
+}
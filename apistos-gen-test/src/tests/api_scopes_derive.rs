@@ -0,0 +1,112 @@
+use actix_web::dev::Payload;
+use actix_web::web::Json;
+use actix_web::{Error, FromRequest, HttpRequest};
+use apistos_core::{ApiComponent, ApiScopes, PathItemDefinition};
+use apistos_gen::{api_operation, ApiScopes, ApiSecurity};
+use assert_json_diff::assert_json_eq;
+use serde_json::json;
+use std::future::Ready;
+
+#[test]
+#[allow(dead_code)]
+fn api_scopes_derive() {
+  #[derive(ApiScopes)]
+  enum Scopes {
+    /// Read your pets
+    ///
+    /// With details
+    #[openapi_scope(name = "read:pets")]
+    ReadPets,
+    #[openapi_scope(name = "write:pets", description = "Modify pets in your account")]
+    WritePets,
+    Admin,
+  }
+
+  assert_eq!(Scopes::ReadPets.scope_name(), "read:pets");
+  assert_eq!(String::from(Scopes::Admin), "Admin".to_string());
+  assert_eq!(String::from(&Scopes::WritePets), "write:pets".to_string());
+
+  let json = serde_json::to_value(Scopes::scopes()).expect("Unable to serialize as Json");
+  assert_json_eq!(
+    json,
+    json!({
+      "Admin": "",
+      "read:pets": "Read your pets\n\nWith details",
+      "write:pets": "Modify pets in your account"
+    })
+  );
+}
+
+#[test]
+#[allow(dead_code, clippy::todo)]
+fn api_operation_typed_scopes() {
+  #[derive(ApiScopes)]
+  enum Scopes {
+    /// Read your pets
+    #[openapi_scope(name = "read:pets")]
+    ReadPets,
+    /// Modify pets in your account
+    #[openapi_scope(name = "write:pets")]
+    WritePets,
+  }
+
+  #[derive(ApiSecurity)]
+  #[openapi_security(
+    name = "oauth",
+    scheme(security_type(oauth2(flows(implicit(
+      authorization_url = "https://authorize.com",
+      scopes_from = Scopes,
+      scopes(scope = "admin", description = "Administrate the store"),
+    )))))
+  )]
+  struct OAuth;
+
+  impl FromRequest for OAuth {
+    type Error = Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(_req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+      todo!()
+    }
+  }
+
+  #[api_operation(security_scope(name = "oauth", scope = Scopes::ReadPets, scope = "admin"))]
+  pub(crate) async fn test(body: Json<String>, _oauth: OAuth) -> Json<String> {
+    body
+  }
+
+  let security_scheme = OAuth::securities();
+  let json = serde_json::to_value(security_scheme).expect("Unable to serialize as Json");
+  assert_json_eq!(
+    json,
+    json!({
+      "oauth": {
+        "flows": {
+          "implicit": {
+            "authorizationUrl": "https://authorize.com",
+            "scopes": {
+              "admin": "Administrate the store",
+              "read:pets": "Read your pets",
+              "write:pets": "Modify pets in your account"
+            }
+          }
+        },
+        "type": "oauth2"
+      }
+    })
+  );
+
+  let operation = __openapi_test::operation();
+  let security = serde_json::to_value(operation.security).expect("Unable to serialize as Json");
+  assert_json_eq!(
+    security,
+    json!([
+      {
+        "oauth": [
+          "read:pets",
+          "admin"
+        ]
+      }
+    ])
+  );
+}
@@ -159,3 +159,30 @@ fn api_security_derive_openid_connect() {
     })
   );
 }
+
+#[test]
+#[allow(dead_code)]
+fn api_security_derive_all_of() {
+  #[derive(ApiSecurity)]
+  #[openapi_security(scheme(security_type(api_key(name = "api_key", api_key_in = "header"))))]
+  struct ApiKey;
+
+  #[derive(ApiSecurity)]
+  #[openapi_security(scheme(security_type(http(scheme = "bearer", bearer_format = "JWT"))))]
+  struct Bearer;
+
+  #[derive(ApiSecurity)]
+  #[openapi_security(all_of(ApiKey, Bearer))]
+  struct ApiKeyAndBearer;
+
+  let securities = ApiKeyAndBearer::securities();
+  assert_eq!(
+    securities.keys().cloned().collect::<Vec<String>>(),
+    vec!["api_key".to_string(), "bearer".to_string()]
+  );
+  assert_eq!(ApiKeyAndBearer::security_requirement_name(), None);
+  assert_eq!(
+    ApiKeyAndBearer::security_requirement_names(),
+    vec!["api_key".to_string(), "bearer".to_string()]
+  );
+}
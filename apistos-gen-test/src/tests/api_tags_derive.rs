@@ -0,0 +1,73 @@
+use actix_web::web::Json;
+use apistos_core::{ApiTags, PathItemDefinition};
+use apistos_gen::{api_operation, ApiTags};
+use assert_json_diff::assert_json_eq;
+use serde_json::json;
+
+#[test]
+#[allow(dead_code)]
+fn api_tags_derive() {
+  #[derive(ApiTags)]
+  enum Tags {
+    /// Everything about your pets
+    ///
+    /// With details
+    #[openapi_tag(
+      name = "pet",
+      external_docs(url = "https://swagger.io", description = "Find out more")
+    )]
+    Pet,
+    /// Access to Petstore orders
+    #[openapi_tag(name = "store", description = "Store operations")]
+    Store,
+    User,
+  }
+
+  assert_eq!(Tags::Pet.tag_name(), "pet");
+  assert_eq!(String::from(Tags::User), "User".to_string());
+
+  let json = serde_json::to_value(Tags::tags()).expect("Unable to serialize as Json");
+  assert_json_eq!(
+    json,
+    json!([
+      {
+        "name": "pet",
+        "description": "Everything about your pets\n\nWith details",
+        "externalDocs": {
+          "description": "Find out more",
+          "url": "https://swagger.io"
+        }
+      },
+      {
+        "name": "store",
+        "description": "Store operations"
+      },
+      {
+        "name": "User"
+      }
+    ])
+  );
+}
+
+#[test]
+#[allow(dead_code)]
+fn api_operation_typed_tags() {
+  #[derive(ApiTags)]
+  enum Tags {
+    #[openapi_tag(name = "pet")]
+    Pet,
+    #[openapi_tag(name = "store")]
+    Store,
+  }
+
+  #[api_operation(tag = Tags::Pet, tag = "plop", tag = Tags::Store)]
+  pub(crate) async fn test(body: Json<String>) -> Json<String> {
+    body
+  }
+
+  let operation = __openapi_test::operation();
+  assert_eq!(
+    operation.tags,
+    vec!["pet".to_string(), "plop".to_string(), "store".to_string()]
+  );
+}
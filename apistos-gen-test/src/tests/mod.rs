@@ -3,5 +3,8 @@ mod api_cookie_derive;
 mod api_error_derive;
 mod api_header_derive;
 mod api_operation;
+mod api_responses_derive;
+mod api_scopes_derive;
 mod api_security_derive;
+mod api_tags_derive;
 mod api_type_derive;
@@ -6,12 +6,18 @@ pub(crate) struct Components<'a> {
   pub(crate) args: &'a [Type],
   pub(crate) responder_wrapper: &'a TokenStream,
   pub(crate) error_codes: &'a [u16],
+  /// Schemas declared in the operation attribute, overriding media types or documenting responses
+  pub(crate) declared_schemas: Vec<&'a syn::Path>,
+  /// Headers of the responses declared in the operation attribute
+  pub(crate) declared_headers: Vec<&'a syn::Path>,
 }
 
 impl<'a> ToTokens for Components<'a> {
   fn to_tokens(&self, tokens: &mut TokenStream) {
     let args = self.args;
     let responder_wrapper = self.responder_wrapper;
+    let declared_schemas = &self.declared_schemas;
+    let declared_headers = &self.declared_headers;
     let error_codes_filter = if self.error_codes.is_empty() {
       quote!()
     } else {
@@ -46,14 +52,33 @@ impl<'a> ToTokens for Components<'a> {
           schemas.push(<#args>::schema());
         )*
         schemas.push(<#responder_wrapper>::schema());
+        #(
+          schemas.push(<#declared_schemas>::schema());
+        )*
         let mut schemas = schemas.into_iter().flatten().collect::<Vec<(String, apistos::reference_or::ReferenceOr<apistos::Schema>)>>();
         #(
           schemas.append(&mut <#args>::child_schemas());
         )*
         schemas.append(&mut <#responder_wrapper>::child_schemas());
+        #(
+          schemas.append(&mut <#declared_schemas>::child_schemas());
+        )*
         let error_schemas = <#responder_wrapper>::error_schemas();
         #error_codes_filter
         component_builder.schemas = std::collections::BTreeMap::from_iter(schemas);
+
+        // only headers still referenced once responses are filtered are documented
+        let responses = <Self as apistos::PathItemDefinition>::operation().responses.responses;
+        component_builder.headers = <#responder_wrapper>::header_components()
+          .into_iter()
+          #(.chain(apistos::response_header_component::<#declared_headers>()))*
+          .filter(|(name, _)| {
+            responses.values().any(|response| match response {
+              apistos::reference_or::ReferenceOr::Object(response) => response.headers.contains_key(name),
+              apistos::reference_or::ReferenceOr::Reference { .. } => false,
+            })
+          })
+          .collect();
         vec![component_builder]
       }
     ))
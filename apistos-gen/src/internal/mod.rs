@@ -1,14 +1,14 @@
 use crate::internal::components::Components;
 use crate::internal::operation::Operation;
-use crate::operation_attr::OperationAttr;
+use crate::internal::utils::extract_doc_comments;
+use crate::operation_attr::{OperationAttr, ResponseDeclaration};
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use proc_macro_error::{abort, emit_error};
 use quote::quote;
 use syn::punctuated::Punctuated;
 use syn::token::Comma;
 use syn::{
-  Expr, FnArg, GenericParam, Ident, ImplGenerics, ItemFn, Lit, Meta, ReturnType, Token, Type, TypeGenerics,
-  TypeTraitObject, WhereClause,
+  FnArg, GenericParam, Ident, ImplGenerics, ItemFn, ReturnType, Token, Type, TypeGenerics, TypeTraitObject, WhereClause,
 };
 
 mod components;
@@ -46,28 +46,7 @@ pub(crate) fn gen_open_api_impl(
       }
     });
 
-    let doc_comments: Vec<String> = item_ast
-      .attrs
-      .iter()
-      .filter(|attr| match attr.path().get_ident() {
-        None => false,
-        Some(attr) => attr == "doc",
-      })
-      .filter_map(|attr| match &attr.meta {
-        Meta::NameValue(nv) => {
-          if let Expr::Lit(ref doc_comment) = nv.value {
-            if let Lit::Str(ref comment) = doc_comment.lit {
-              Some(comment.value().trim().to_string())
-            } else {
-              None
-            }
-          } else {
-            None
-          }
-        }
-        Meta::Path(_) | Meta::List(_) => None,
-      })
-      .collect();
+    let doc_comments: Vec<String> = extract_doc_comments(&item_ast.attrs);
     let description = &*doc_comments
       .clone()
       .into_iter()
@@ -91,19 +70,46 @@ pub(crate) fn gen_open_api_impl(
       tags: &operation_attribute.tags,
       scopes: operation_attribute.scopes,
       error_codes: &operation_attribute.error_codes,
-      consumes: operation_attribute.consumes.as_ref(),
-      produces: operation_attribute.produces.as_ref(),
+      consumes: &operation_attribute.consumes,
+      produces: &operation_attribute.produces,
+      responses: &operation_attribute.responses,
     };
     let components = Components {
       args: &args,
       responder_wrapper,
       error_codes: &operation_attribute.error_codes,
+      declared_schemas: operation_attribute
+        .consumes
+        .schemas()
+        .into_iter()
+        .chain(operation_attribute.produces.schemas())
+        .chain(
+          operation_attribute
+            .responses
+            .iter()
+            .filter_map(ResponseDeclaration::schema),
+        )
+        .collect(),
+      declared_headers: operation_attribute
+        .responses
+        .iter()
+        .flat_map(ResponseDeclaration::headers)
+        .collect(),
     };
 
+    let handler_name = item_ast.sig.ident.to_string();
+    let security_disabled = operation_attribute.security_disabled;
+
     quote!(
       fn is_visible() -> bool {
         true
       }
+      fn handler_name() -> Option<&'static str> {
+        Some(#handler_name)
+      }
+      fn security_disabled() -> bool {
+        #security_disabled
+      }
       #operation
       #components
     )
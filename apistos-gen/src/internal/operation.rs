@@ -1,4 +1,5 @@
 use crate::internal::security::Security;
+use crate::operation_attr::{MediaTypes, ResponseDeclaration, TypedName};
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use std::collections::BTreeMap;
@@ -11,11 +12,12 @@ pub(crate) struct Operation<'a> {
   pub(crate) deprecated: Option<bool>,
   pub(crate) summary: Option<&'a String>,
   pub(crate) description: Option<&'a str>,
-  pub(crate) tags: &'a [String],
-  pub(crate) scopes: BTreeMap<String, Vec<String>>,
+  pub(crate) tags: &'a [TypedName],
+  pub(crate) scopes: BTreeMap<String, Vec<TypedName>>,
   pub(crate) error_codes: &'a [u16],
-  pub(crate) consumes: Option<&'a String>,
-  pub(crate) produces: Option<&'a String>,
+  pub(crate) consumes: &'a MediaTypes,
+  pub(crate) produces: &'a MediaTypes,
+  pub(crate) responses: &'a [ResponseDeclaration],
 }
 
 impl<'a> ToTokens for Operation<'a> {
@@ -49,7 +51,7 @@ impl<'a> ToTokens for Operation<'a> {
       let tags = self.tags;
       quote! {
         let tags = vec![
-          #(#tags.to_owned(),)*
+          #(#tags,)*
         ];
         operation_builder.tags = tags;
       }
@@ -84,15 +86,21 @@ impl<'a> ToTokens for Operation<'a> {
       }
     };
 
-    let consumes = if let Some(consumes) = self.consumes {
-      quote!(Some(#consumes.to_string()))
-    } else {
-      quote!(None)
-    };
-    let produces = if let Some(produces) = self.produces {
-      quote!(Some(#produces.to_string()))
+    let declared_statuses = self.responses;
+    let consumes = self.consumes;
+    let produces = self.produces;
+    let declared_responses = if self.responses.is_empty() {
+      quote!()
     } else {
-      quote!(None)
+      let declared_responses = self.responses;
+      quote! {
+        // declared responses replace the successful responses inferred from the responder
+        responses.responses.retain(|status, _| status.parse::<u16>().map_or(true, |status| status >= 400));
+        #(
+          let (status, response) = #declared_responses;
+          responses.responses.insert(status, apistos::reference_or::ReferenceOr::Object(response));
+        )*
+      }
     };
     tokens.extend(quote!(
       fn operation() -> apistos::paths::Operation {
@@ -100,25 +108,18 @@ impl<'a> ToTokens for Operation<'a> {
         let mut operation_builder = apistos::paths::Operation::default();
 
         let mut body_requests = vec![];
+        let consumes: Vec<(String, Option<apistos::reference_or::ReferenceOr<apistos::Schema>>)> = #consumes;
         #(
           let mut request_body = <#args>::request_body();
-          let consumes: Option<String> = #consumes;
-          if let Some(consumes) = consumes {
-            request_body
-              .as_mut()
-              .map(|t|
-                t.content = t
-                  .content
-                  .values()
-                  .map(|v| (consumes.clone(), v.clone())).collect::<std::collections::BTreeMap<String, apistos::paths::MediaType>>()
-              );
+          if !consumes.is_empty() {
+            if let Some(request_body) = request_body.as_mut() {
+              request_body.content = apistos::override_media_types(&request_body.content, consumes.clone());
+            }
           }
           body_requests.push(request_body);
         )*
         let body_requests = body_requests.into_iter().flatten().collect::<Vec<apistos::paths::RequestBody>>();
-        for body_request in body_requests {
-          operation_builder.request_body = Some(apistos::reference_or::ReferenceOr::Object(body_request));
-        }
+        apistos::document_request_bodies(&mut operation_builder, body_requests);
 
         let mut parameters = vec![];
         #(
@@ -128,7 +129,36 @@ impl<'a> ToTokens for Operation<'a> {
           operation_builder.parameters = parameters.into_iter().map(apistos::reference_or::ReferenceOr::Object).collect();
         }
 
-        if let Some(responses) = <#responder_wrapper>::responses(#produces) {
+        let produces: Vec<(String, Option<apistos::reference_or::ReferenceOr<apistos::Schema>>)> = #produces;
+        if let Some(mut responses) = <#responder_wrapper>::responses(produces.first().map(|(content_type, _)| content_type.clone())) {
+          if !produces.is_empty() {
+            for (status, response) in &mut responses.responses {
+              let success = status.parse::<u16>().map_or(false, |status| status < 400);
+              if let apistos::reference_or::ReferenceOr::Object(response) = response {
+                if success && !response.content.is_empty() {
+                  response.content = apistos::override_media_types(&response.content, produces.clone());
+                }
+              }
+            }
+          }
+          #declared_responses
+          // responses documented by the handler win over extractors failures
+          #(
+            for (status, response) in <#args>::error_responses() {
+              responses
+                .responses
+                .entry(status)
+                .or_insert(apistos::reference_or::ReferenceOr::Object(response));
+            }
+          )*
+          #(
+            for (status, response) in <#args>::extractor_error_responses() {
+              responses
+                .responses
+                .entry(status)
+                .or_insert(apistos::reference_or::ReferenceOr::Object(response));
+            }
+          )*
           #error_codes_filter
           operation_builder.responses = responses;
         }
@@ -137,7 +167,7 @@ impl<'a> ToTokens for Operation<'a> {
           #security
         };
         if !securities.is_empty() {
-          operation_builder.security = securities;
+          operation_builder.security = Some(securities);
         }
 
         operation_builder.operation_id = #operation_id;
@@ -151,6 +181,30 @@ impl<'a> ToTokens for Operation<'a> {
 
         operation_builder
       }
+
+      fn extractor_error_statuses() -> Vec<String> {
+        use apistos::ApiComponent;
+        let documented_by_handler: Vec<String> = std::iter::empty()
+          .chain(<#responder_wrapper>::responses(None).into_iter().flat_map(|responses| responses.responses.into_keys()))
+          #(.chain([(#declared_statuses).0]))*
+          #(.chain(<#args>::error_responses().into_iter().map(|(status, _)| status)))*
+          .collect();
+        let responses = <Self as apistos::PathItemDefinition>::operation().responses.responses;
+        std::iter::empty()
+          #(.chain(<#args>::extractor_error_responses().into_iter().map(|(status, _)| status)))*
+          .fold(vec![], |mut statuses: Vec<String>, status| {
+            if responses.contains_key(&status) && !documented_by_handler.contains(&status) && !statuses.contains(&status) {
+              statuses.push(status);
+            }
+            statuses
+          })
+      }
+
+      fn ignored_request_bodies() -> usize {
+        use apistos::ApiComponent;
+        let request_bodies: &[bool] = &[#(<#args>::request_body().is_some()),*];
+        request_bodies.iter().filter(|documented| **documented).count().saturating_sub(1)
+      }
     ))
   }
 }
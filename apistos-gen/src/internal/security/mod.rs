@@ -1,3 +1,4 @@
+use crate::operation_attr::TypedName;
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use std::collections::BTreeMap;
@@ -7,7 +8,7 @@ pub(crate) mod models;
 
 pub(crate) struct Security<'a> {
   pub(crate) args: &'a [Type],
-  pub(crate) scopes: &'a BTreeMap<String, Vec<String>>,
+  pub(crate) scopes: &'a BTreeMap<String, Vec<TypedName>>,
 }
 
 impl<'a> ToTokens for Security<'a> {
@@ -21,7 +22,7 @@ impl<'a> ToTokens for Security<'a> {
       let mut scopes_tokens = quote!();
       for (scope_name, scopes) in self.scopes {
         scopes_tokens.extend(quote!(
-          (#scope_name.to_string(), vec![#(#scopes.to_string(),)*]),
+          (#scope_name.to_string(), vec![#(#scopes,)*]),
         ));
       }
       quote! {
@@ -36,17 +37,22 @@ impl<'a> ToTokens for Security<'a> {
       let mut securities = vec![];
       let needed_scopes: std::collections::BTreeMap<String, Vec<String>> = #scopes
       #(
-        if !<#args>::required() {
-          needs_empty_security = true;
-        }
-        let mut security_requirements = vec![];
-        if let Some(security_requirement_name) = <#args>::security_requirement_name() {
-          let scopes: Vec<String> = needed_scopes.get(&security_requirement_name).cloned().unwrap_or_default();
-          security_requirements.push(apistos::security::SecurityRequirement {
-            requirements: std::collections::BTreeMap::from_iter(vec![(security_requirement_name, scopes)]),
+        // securities of a single argument must all be satisfied while each argument is an alternative
+        let security_requirement_names = <#args>::security_requirement_names();
+        if !security_requirement_names.is_empty() {
+          if !<#args>::required() {
+            needs_empty_security = true;
+          }
+          securities.push(apistos::security::SecurityRequirement {
+            requirements: security_requirement_names
+              .into_iter()
+              .map(|name| {
+                let scopes: Vec<String> = needed_scopes.get(&name).cloned().unwrap_or_default();
+                (name, scopes)
+              })
+              .collect(),
           });
         }
-        securities.append(&mut security_requirements);
       )*
       if needs_empty_security {
         securities.push(apistos::security::SecurityRequirement::default());
@@ -147,6 +147,8 @@ pub(crate) struct OauthImplicit {
   pub(crate) refresh_url: Option<String>,
   #[darling(multiple)]
   pub(crate) scopes: Vec<Scope>,
+  /// An enum deriving `ApiScopes` declaring the flow scopes.
+  pub(crate) scopes_from: Option<syn::Path>,
 }
 
 impl ToTokens for OauthImplicit {
@@ -157,16 +159,7 @@ impl ToTokens for OauthImplicit {
       .clone()
       .map(|r| quote!(Some(#r.to_string())))
       .unwrap_or_else(|| quote!(None));
-    let scopes = if self.scopes.is_empty() {
-      quote!(std::collections::BTreeMap::default())
-    } else {
-      let scopes = &self.scopes;
-      quote! {
-        std::collections::BTreeMap::from_iter([
-          #(#scopes,)*
-        ])
-      }
-    };
+    let scopes = flow_scopes(&self.scopes, self.scopes_from.as_ref());
     tokens.extend(quote! {
       apistos::security::OauthImplicit {
         authorization_url: #authorization_url.to_string(),
@@ -183,6 +176,8 @@ pub(crate) struct OauthToken {
   pub(crate) refresh_url: Option<String>,
   #[darling(multiple)]
   pub(crate) scopes: Vec<Scope>,
+  /// An enum deriving `ApiScopes` declaring the flow scopes.
+  pub(crate) scopes_from: Option<syn::Path>,
 }
 
 impl ToTokens for OauthToken {
@@ -193,16 +188,7 @@ impl ToTokens for OauthToken {
       .clone()
       .map(|r| quote!(Some(#r.to_string())))
       .unwrap_or_else(|| quote!(None));
-    let scopes = if self.scopes.is_empty() {
-      quote!(std::collections::BTreeMap::default())
-    } else {
-      let scopes = &self.scopes;
-      quote! {
-        std::collections::BTreeMap::from_iter([
-          #(#scopes,)*
-        ])
-      }
-    };
+    let scopes = flow_scopes(&self.scopes, self.scopes_from.as_ref());
     tokens.extend(quote! {
       apistos::security::OauthToken {
         token_url: #token_url.to_string(),
@@ -213,6 +199,26 @@ impl ToTokens for OauthToken {
   }
 }
 
+fn flow_scopes(scopes: &[Scope], scopes_from: Option<&syn::Path>) -> TokenStream {
+  let scopes = if scopes.is_empty() {
+    quote!(std::collections::BTreeMap::default())
+  } else {
+    quote! {
+      std::collections::BTreeMap::from_iter([
+        #(#scopes,)*
+      ])
+    }
+  };
+  match scopes_from {
+    None => scopes,
+    Some(scopes_from) => quote!({
+      let mut scopes: std::collections::BTreeMap<String, String> = #scopes;
+      scopes.append(&mut <#scopes_from as apistos::ApiScopes>::scopes());
+      scopes
+    }),
+  }
+}
+
 #[derive(FromMeta, Clone)]
 pub(crate) struct Scope {
   pub(crate) scope: String,
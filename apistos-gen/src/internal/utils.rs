@@ -1,4 +1,4 @@
-use syn::Attribute;
+use syn::{Attribute, Expr, Lit, Meta};
 
 pub(crate) fn extract_deprecated_from_attr(attrs: &[Attribute]) -> Option<bool> {
   attrs.iter().find_map(|attr| {
@@ -9,3 +9,27 @@ pub(crate) fn extract_deprecated_from_attr(attrs: &[Attribute]) -> Option<bool>
     }
   })
 }
+
+pub(crate) fn extract_doc_comments(attrs: &[Attribute]) -> Vec<String> {
+  attrs
+    .iter()
+    .filter(|attr| match attr.path().get_ident() {
+      None => false,
+      Some(attr) => attr == "doc",
+    })
+    .filter_map(|attr| match &attr.meta {
+      Meta::NameValue(nv) => {
+        if let Expr::Lit(ref doc_comment) = nv.value {
+          if let Lit::Str(ref comment) = doc_comment.lit {
+            Some(comment.value().trim().to_string())
+          } else {
+            None
+          }
+        } else {
+          None
+        }
+      }
+      Meta::Path(_) | Meta::List(_) => None,
+    })
+    .collect()
+}
@@ -3,12 +3,15 @@
 //! ⚠️ This crate is not indented to be used by itself. Please use [**apistos**](https://crates.io/crates/apistos) instead.
 
 use crate::internal::schemas::Schemas;
-use crate::internal::utils::extract_deprecated_from_attr;
+use crate::internal::utils::{extract_deprecated_from_attr, extract_doc_comments};
 use crate::internal::{extract_generics_params, gen_item_ast, gen_open_api_impl};
 use crate::openapi_cookie_attr::parse_openapi_cookie_attrs;
 use crate::openapi_error_attr::parse_openapi_error_attrs;
 use crate::openapi_header_attr::parse_openapi_header_attrs;
+use crate::openapi_response_attr::{parse_openapi_response_attrs, response_status_code};
+use crate::openapi_scope_attr::parse_openapi_scope_attrs;
 use crate::openapi_security_attr::parse_openapi_security_attrs;
+use crate::openapi_tag_attr::parse_openapi_tag_attrs;
 use crate::operation_attr::parse_openapi_operation_attrs;
 use convert_case::{Case, Casing};
 use darling::ast::NestedMeta;
@@ -16,13 +19,16 @@ use darling::Error;
 use proc_macro::TokenStream;
 use proc_macro_error::{abort, proc_macro_error, OptionExt};
 use quote::quote;
-use syn::{DeriveInput, Ident, ItemFn};
+use syn::{Data, DeriveInput, Fields, Ident, ItemFn};
 
 mod internal;
 mod openapi_cookie_attr;
 mod openapi_error_attr;
 mod openapi_header_attr;
+mod openapi_response_attr;
+mod openapi_scope_attr;
 mod openapi_security_attr;
+mod openapi_tag_attr;
 mod operation_attr;
 
 const OPENAPI_STRUCT_PREFIX: &str = "__openapi_";
@@ -165,7 +171,12 @@ pub fn derive_api_component(input: TokenStream) -> TokenStream {
 ///
 /// # `#[openapi_security(...)]` options:
 /// - `name = "..."` an optional name for your security definition. If not provided, the struct ident will be used.
-/// - `scheme(...)` a **required** parameter with:
+/// - `all_of(...)` a list of types deriving `ApiSecurity` which must all be satisfied, documented as a single security
+///   requirement. Can't be used with `name` or `scheme`.
+/// - `extractor` an optional flag generating an `actix_web::FromRequest` implementation reading the credential from
+///   the documented location and delegating its validation to `apistos::ApiSecurityValidator`. Can't be used with
+///   `all_of`.
+/// - `scheme(...)` a **required** parameter, unless `all_of` is used, with:
 ///   - `description = "..."` an optional description
 ///   - `security_type(...)` a **required** parameter with one of
 ///     - `oauth2(flows(...))` with
@@ -173,6 +184,7 @@ pub fn derive_api_component(input: TokenStream) -> TokenStream {
 ///       - `password(...)` with `token_url = "..."` a **required** parameter, `refresh_url = "..."` an optional parameter and `scopes(scope = "...", description = "...")` a list of scopes
 ///       - `client_credentials(...)` with `token_url = "..."` a **required** parameter, `refresh_url = "..."` an optional parameter and `scopes(scope = "...", description = "...")` a list of scopes
 ///       - `authorization_code(...)` with `token_url = "..."` a **required** parameter, `refresh_url = "..."` an optional parameter and `scopes(scope = "...", description = "...")` a list of scopes
+///       - every flow also accepts `scopes_from = Scopes` with `Scopes` an enum deriving [ApiScopes](derive.ApiScopes.html)
 ///     - `api_key(...)` with
 ///       - `name = "..."` a **required** parameter
 ///       - `api_key_in = "..."` a **required** parameter being one of `query`, `header` or `cookie`
@@ -215,6 +227,50 @@ pub fn derive_api_component(input: TokenStream) -> TokenStream {
 /// pub struct ApiKey;
 /// ```
 ///
+/// ## **all_of**
+/// ```rust
+/// use apistos::ApiSecurity;
+///
+/// #[derive(ApiSecurity)]
+/// #[openapi_security(scheme(security_type(api_key(name = "api_key", api_key_in = "header"))))]
+/// pub struct ApiKey;
+///
+/// #[derive(ApiSecurity)]
+/// #[openapi_security(scheme(security_type(http(scheme = "bearer", bearer_format = "JWT"))))]
+/// pub struct Bearer;
+///
+/// // documented as `{ "api_key": [], "bearer": [] }`
+/// #[derive(ApiSecurity)]
+/// #[openapi_security(all_of(ApiKey, Bearer))]
+/// pub struct ApiKeyAndBearer;
+/// ```
+///
+/// ## **extractor**
+/// ```rust
+/// use actix_web::error::ErrorForbidden;
+/// use actix_web::{Error, HttpRequest};
+/// use apistos::{ApiSecurity, ApiSecurityValidator};
+/// use std::future::{ready, Ready};
+///
+/// #[derive(ApiSecurity)]
+/// #[openapi_security(extractor, scheme(security_type(api_key(name = "api_key", api_key_in = "header"))))]
+/// pub struct ApiKey(String);
+///
+/// impl ApiSecurityValidator for ApiKey {
+///   type Error = Error;
+///   type Future = Ready<Result<Self, Self::Error>>;
+///
+///   // called with the `api_key` header value, a missing header is answered with `401 Unauthorized`
+///   fn validate(_req: &HttpRequest, credential: String) -> Self::Future {
+///     if credential == "secret" {
+///       ready(Ok(ApiKey(credential)))
+///     } else {
+///       ready(Err(ErrorForbidden("Invalid api key")))
+///     }
+///   }
+/// }
+/// ```
+///
 /// ## **open_id_connect**
 /// ```rust
 /// use apistos::ApiSecurity;
@@ -239,7 +295,10 @@ pub fn derive_api_security(input: TokenStream) -> TokenStream {
   let openapi_security_attributes = parse_openapi_security_attrs(&attrs, security_name).expect_or_abort(
     "expected #[openapi_security(...)] attribute to be present when used with ApiSecurity derive trait",
   );
-  let security_name = &openapi_security_attributes.name;
+  let securities = openapi_security_attributes.securities();
+  let security_requirement_name = openapi_security_attributes.security_requirement_name();
+  let security_requirement_names = openapi_security_attributes.security_requirement_names();
+  let extractor = openapi_security_attributes.extractor_impl(&ident, &generics);
 
   let (_, ty_generics, where_clause) = generics.split_for_impl();
   quote!(
@@ -254,13 +313,19 @@ pub fn derive_api_security(input: TokenStream) -> TokenStream {
       }
 
       fn securities() -> std::collections::BTreeMap<String, apistos::security::SecurityScheme> {
-        #openapi_security_attributes
+        #securities
       }
 
       fn security_requirement_name() -> Option<String> {
-        Some(#security_name.to_string())
+        #security_requirement_name
+      }
+
+      fn security_requirement_names() -> Vec<String> {
+        #security_requirement_names
       }
     }
+
+    #extractor
   )
   .into()
 }
@@ -290,6 +355,8 @@ pub fn derive_api_security(input: TokenStream) -> TokenStream {
 /// - `description = "..."` an optional description for the header
 /// - `required = false` an optional parameter, default value is false
 /// - `deprecated = false` an optional parameter, default value is false
+/// - `component` an optional flag documenting the header once in `components/headers`, responses declaring it as a
+///   response header referencing it
 ///
 /// Because this macro require [JsonSchema](https://docs.rs/schemars/latest/schemars/trait.JsonSchema.html), all attributes supported by [JsonSchema](https://docs.rs/schemars/latest/schemars/trait.JsonSchema.html) are forward to
 /// this implementation.
@@ -383,6 +450,208 @@ pub fn derive_api_cookie(input: TokenStream) -> TokenStream {
   .into()
 }
 
+/// Generate a closed set of documented tags from an enum.
+///
+/// Each variant is a tag. Tag names default to the variant name and descriptions to the variant doc comments.
+/// The generated `ApiTags` implementation gives access to every tag through `Tags::tags()` (meant for `Spec.tags`)
+/// and variants can be used as tags in [api_operation](attr.api_operation.html) (`tag = Tags::Pet`) or anywhere a tag name
+/// is expected through `Into<String>`.
+///
+/// ```rust
+/// use apistos::ApiTags;
+///
+/// #[derive(ApiTags)]
+/// pub enum Tags {
+///   /// Everything about your pets
+///   #[openapi_tag(name = "pet", external_docs(url = "https://swagger.io", description = "Find out more"))]
+///   Pet,
+///   /// Access to Petstore orders
+///   #[openapi_tag(name = "store")]
+///   Store,
+/// }
+/// ```
+///
+/// # `#[openapi_tag(...)]` options:
+/// - `name = "..."` an optional tag name, default is the variant name
+/// - `description = "..."` an optional description, default is the variant doc comments
+/// - `external_docs(...)` optional external documentation with
+///   - `url = "..."` a **required** url
+///   - `description = "..."` an optional description
+#[proc_macro_error]
+#[proc_macro_derive(ApiTags, attributes(openapi_tag))]
+pub fn derive_api_tags(input: TokenStream) -> TokenStream {
+  let input = syn::parse_macro_input!(input as DeriveInput);
+  let DeriveInput {
+    attrs: _attrs,
+    ident,
+    data,
+    generics,
+    vis: _vis,
+  } = input;
+
+  let variants = match data {
+    Data::Enum(data_enum) => data_enum.variants,
+    Data::Struct(_) | Data::Union(_) => abort!(ident.span(), "ApiTags can only be derived for enums"),
+  };
+
+  let mut tag_names = vec![];
+  let mut tags = vec![];
+  for variant in variants {
+    if !matches!(variant.fields, Fields::Unit) {
+      abort!(variant.ident.span(), "ApiTags variants can't have fields");
+    }
+    let openapi_tag_attributes = parse_openapi_tag_attrs(&variant.attrs).unwrap_or_default();
+    let variant_ident = variant.ident;
+    let name = openapi_tag_attributes.name.unwrap_or_else(|| variant_ident.to_string());
+    let description = openapi_tag_attributes.description.or_else(|| {
+      let doc_comments = extract_doc_comments(&variant.attrs);
+      (!doc_comments.is_empty()).then(|| doc_comments.join("\n"))
+    });
+    let description = match description {
+      None => quote!(None),
+      Some(desc) => quote!(Some(#desc.to_string())),
+    };
+    let external_docs = match openapi_tag_attributes.external_docs {
+      None => quote!(None),
+      Some(external_docs) => quote!(Some(#external_docs)),
+    };
+
+    tag_names.push(quote!(Self::#variant_ident => #name));
+    tags.push(quote!(
+      apistos::tag::Tag {
+        name: #name.to_string(),
+        description: #description,
+        external_docs: #external_docs,
+        ..Default::default()
+      }
+    ));
+  }
+
+  let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+  quote!(
+    #[automatically_derived]
+    impl #impl_generics apistos::ApiTags for #ident #ty_generics #where_clause {
+      fn tag_name(&self) -> &'static str {
+        match self {
+          #(#tag_names,)*
+        }
+      }
+
+      fn tags() -> Vec<apistos::tag::Tag> {
+        vec![#(#tags,)*]
+      }
+    }
+
+    #[automatically_derived]
+    impl #impl_generics From<#ident #ty_generics> for String #where_clause {
+      fn from(tag: #ident #ty_generics) -> Self {
+        apistos::ApiTags::tag_name(&tag).to_string()
+      }
+    }
+
+    #[automatically_derived]
+    impl #impl_generics From<&#ident #ty_generics> for String #where_clause {
+      fn from(tag: &#ident #ty_generics) -> Self {
+        apistos::ApiTags::tag_name(tag).to_string()
+      }
+    }
+  )
+  .into()
+}
+
+/// Generate a closed set of documented oauth2 scopes from an enum.
+///
+/// Each variant is a scope. Scope names default to the variant name and descriptions to the variant doc comments.
+/// The generated `ApiScopes` implementation gives access to every scope through `Scopes::scopes()` which can populate
+/// oauth2 flows of an [ApiSecurity](derive.ApiSecurity.html) (`scopes_from = Scopes`). Variants can be used as scopes
+/// in [api_operation](attr.api_operation.html) (`security_scope(name = "...", scope = Scopes::ReadPets)`) or anywhere
+/// a scope name is expected through `Into<String>`.
+///
+/// ```rust
+/// use apistos::ApiScopes;
+///
+/// #[derive(ApiScopes)]
+/// pub enum Scopes {
+///   /// Read your pets
+///   #[openapi_scope(name = "read:pets")]
+///   ReadPets,
+///   /// Modify pets in your account
+///   #[openapi_scope(name = "write:pets")]
+///   WritePets,
+/// }
+/// ```
+///
+/// # `#[openapi_scope(...)]` options:
+/// - `name = "..."` an optional scope name, default is the variant name
+/// - `description = "..."` an optional description, default is the variant doc comments
+#[proc_macro_error]
+#[proc_macro_derive(ApiScopes, attributes(openapi_scope))]
+pub fn derive_api_scopes(input: TokenStream) -> TokenStream {
+  let input = syn::parse_macro_input!(input as DeriveInput);
+  let DeriveInput {
+    attrs: _attrs,
+    ident,
+    data,
+    generics,
+    vis: _vis,
+  } = input;
+
+  let variants = match data {
+    Data::Enum(data_enum) => data_enum.variants,
+    Data::Struct(_) | Data::Union(_) => abort!(ident.span(), "ApiScopes can only be derived for enums"),
+  };
+
+  let mut scope_names = vec![];
+  let mut scopes = vec![];
+  for variant in variants {
+    if !matches!(variant.fields, Fields::Unit) {
+      abort!(variant.ident.span(), "ApiScopes variants can't have fields");
+    }
+    let openapi_scope_attributes = parse_openapi_scope_attrs(&variant.attrs).unwrap_or_default();
+    let variant_ident = variant.ident;
+    let name = openapi_scope_attributes
+      .name
+      .unwrap_or_else(|| variant_ident.to_string());
+    let description = openapi_scope_attributes
+      .description
+      .unwrap_or_else(|| extract_doc_comments(&variant.attrs).join("\n"));
+
+    scope_names.push(quote!(Self::#variant_ident => #name));
+    scopes.push(quote!((#name.to_string(), #description.to_string())));
+  }
+
+  let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+  quote!(
+    #[automatically_derived]
+    impl #impl_generics apistos::ApiScopes for #ident #ty_generics #where_clause {
+      fn scope_name(&self) -> &'static str {
+        match self {
+          #(#scope_names,)*
+        }
+      }
+
+      fn scopes() -> std::collections::BTreeMap<String, String> {
+        std::collections::BTreeMap::from_iter([#(#scopes,)*])
+      }
+    }
+
+    #[automatically_derived]
+    impl #impl_generics From<#ident #ty_generics> for String #where_clause {
+      fn from(scope: #ident #ty_generics) -> Self {
+        apistos::ApiScopes::scope_name(&scope).to_string()
+      }
+    }
+
+    #[automatically_derived]
+    impl #impl_generics From<&#ident #ty_generics> for String #where_clause {
+      fn from(scope: &#ident #ty_generics) -> Self {
+        apistos::ApiScopes::scope_name(scope).to_string()
+      }
+    }
+  )
+  .into()
+}
+
 /// Generate a reusable OpenAPI error schema.
 ///
 /// This `#[derive]` macro should be used in combinaison with [api_operation](attr.api_operation.html).
@@ -410,6 +679,8 @@ pub fn derive_api_cookie(input: TokenStream) -> TokenStream {
 /// - `status(...)` a list of possible error status with
 ///   - `code = 000` a **required** http status code
 ///   - `description = "..."` an optional description, default is the canonical reason of the given status code
+///   - `header = ...` an optional type deriving [ApiHeader](derive.ApiHeader.html) documented as response header, e.g.
+///     `status(code = 429, header = RetryAfter)`, can be repeated
 #[proc_macro_error]
 #[proc_macro_derive(ApiErrorComponent, attributes(openapi_error))]
 pub fn derive_api_error(input: TokenStream) -> TokenStream {
@@ -436,6 +707,145 @@ pub fn derive_api_error(input: TokenStream) -> TokenStream {
   .into()
 }
 
+/// Generate a responder documenting every successful outcome of a handler.
+///
+/// This `#[derive]` macro should be used on an enum in which each variant is annotated with `#[openapi_response(...)]`.
+/// Unit variants answer an empty body, variants with a single unnamed field answer this field as json. The field type
+/// must implement [ApiComponent](trait.ApiComponent.html) and `Serialize`.
+///
+/// Both [ApiComponent](trait.ApiComponent.html) and `actix_web::Responder` are implemented.
+///
+/// ```rust
+/// use apistos::{ApiComponent, ApiResponses};
+/// use schemars::JsonSchema;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize, JsonSchema, ApiComponent)]
+/// pub struct Pet {
+///   pub name: String,
+/// }
+///
+/// #[derive(ApiResponses)]
+/// pub enum PetResponse {
+///   /// The pet already exists
+///   #[openapi_response(status = 200)]
+///   Found(Pet),
+///   #[openapi_response(status = 201, description = "The pet has been created")]
+///   Created(Pet),
+///   #[openapi_response(status = 204)]
+///   Deleted,
+/// }
+/// ```
+///
+/// # `#[openapi_response(...)]` options:
+/// - `status = 000` a **required** http status code, from 100 to 599, checked at compile time
+/// - `description = "..."` an optional description, default is the variant doc comment or the canonical reason of the given status code
+///
+/// Variants answering the same status are documented using a `oneOf`. Headers can't be declared on variants, the
+/// responder having no value to answer them with. Wrap the enum using `apistos::actix::WithHeader` to answer and
+/// document a header.
+#[proc_macro_error]
+#[proc_macro_derive(ApiResponses, attributes(openapi_response))]
+pub fn derive_api_responses(input: TokenStream) -> TokenStream {
+  let input = syn::parse_macro_input!(input as DeriveInput);
+  let DeriveInput {
+    attrs: _attrs,
+    ident,
+    data,
+    generics,
+    vis: _vis,
+  } = input;
+
+  let variants = match data {
+    Data::Enum(data_enum) => data_enum.variants,
+    Data::Struct(_) | Data::Union(_) => abort!(ident.span(), "ApiResponses can only be derived for enums"),
+  };
+
+  let mut bodies = vec![];
+  let mut responses = vec![];
+  let mut responders = vec![];
+  for variant in variants {
+    let openapi_response_attributes = parse_openapi_response_attrs(&variant.attrs).expect_or_abort(
+      "expected #[openapi_response(...)] attribute to be present on each variant when used with ApiResponses derive trait",
+    );
+    let variant_ident = variant.ident;
+    if let Some(header) = openapi_response_attributes.headers.first() {
+      abort!(
+        header,
+        "headers can't be declared on ApiResponses variants as they wouldn't be answered, wrap the responder using `apistos::actix::WithHeader` instead"
+      );
+    }
+    // aborts on invalid status codes so that the answered status is the documented one
+    response_status_code(openapi_response_attributes.status);
+    let status = openapi_response_attributes.status;
+    let status_code = quote!(::actix_web::http::StatusCode::from_u16(#status)
+      .unwrap_or(::actix_web::http::StatusCode::INTERNAL_SERVER_ERROR));
+    let body = match variant.fields {
+      Fields::Unit => {
+        responders.push(quote!(Self::#variant_ident => ::actix_web::HttpResponse::build(#status_code).finish()));
+        None
+      }
+      Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+        responders
+          .push(quote!(Self::#variant_ident(body) => ::actix_web::HttpResponse::build(#status_code).json(body)));
+        fields.unnamed.into_iter().next().map(|field| field.ty)
+      }
+      Fields::Unnamed(_) | Fields::Named(_) => abort!(
+        variant_ident.span(),
+        "ApiResponses variants can only be unit variants or have a single unnamed field"
+      ),
+    };
+    responses.push(openapi_response_attributes.response(body.as_ref(), &extract_doc_comments(&variant.attrs)));
+    bodies.extend(body);
+  }
+
+  let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+  quote!(
+    #[automatically_derived]
+    impl #impl_generics apistos::ApiComponent for #ident #ty_generics #where_clause {
+      fn child_schemas() -> Vec<(String, apistos::reference_or::ReferenceOr<apistos::Schema>)> {
+        let mut schemas = vec![];
+        #(
+          schemas.extend(<#bodies as apistos::ApiComponent>::schema());
+          schemas.append(&mut <#bodies as apistos::ApiComponent>::child_schemas());
+        )*
+        schemas
+      }
+
+      fn schema() -> Option<(String, apistos::reference_or::ReferenceOr<apistos::Schema>)> {
+        None
+      }
+
+      fn responses(_content_type: Option<String>) -> Option<apistos::paths::Responses> {
+        let mut responses = std::collections::BTreeMap::new();
+        #(#responses)*
+        Some(apistos::paths::Responses {
+          responses,
+          ..Default::default()
+        })
+      }
+
+      fn header_components() -> Vec<(String, apistos::reference_or::ReferenceOr<apistos::paths::Header>)> {
+        std::iter::empty()
+          #(.chain(<#bodies as apistos::ApiComponent>::header_components()))*
+          .collect()
+      }
+    }
+
+    #[automatically_derived]
+    impl #impl_generics ::actix_web::Responder for #ident #ty_generics #where_clause {
+      type Body = ::actix_web::body::BoxBody;
+
+      fn respond_to(self, _req: &::actix_web::HttpRequest) -> ::actix_web::HttpResponse<Self::Body> {
+        match self {
+          #(#responders,)*
+        }
+      }
+    }
+  )
+  .into()
+}
+
 /// Operation attribute macro implementing [PathItemDefinition](path_item_definition/trait.PathItemDefinition.html) for the decorated handler function.
 ///
 /// ```rust
@@ -444,7 +854,7 @@ pub fn derive_api_error(input: TokenStream) -> TokenStream {
 /// use actix_web::http::StatusCode;
 /// use actix_web::ResponseError;
 /// use core::fmt::Formatter;
-/// use apistos::actix::CreatedJson;
+/// use apistos::actix::{CreatedJson, StatusJson};
 /// use apistos::{api_operation, ApiComponent, ApiErrorComponent};
 /// use schemars::JsonSchema;
 /// use serde::{Serialize, Deserialize};
@@ -484,7 +894,7 @@ pub fn derive_api_error(input: TokenStream) -> TokenStream {
 /// pub(crate) async fn test(
 ///   body: Json<Test>,
 /// ) -> Result<CreatedJson<Test>, ErrorResponse> {
-///   Ok(CreatedJson(body.0))
+///   Ok(StatusJson(body.0))
 /// }
 /// ```
 ///
@@ -493,16 +903,34 @@ pub fn derive_api_error(input: TokenStream) -> TokenStream {
 ///  strictly associated to this operation will be document in the resulting openapi definition.
 ///   - `deprecated` a bool indicating the operation is deprecated. Deprecation can also be declared
 ///  with rust `#[deprecated]` decorator.
-///   - `operation_id = "..."` an optional operation id for this operation. Default is the handler fn name.
+///   - `operation_id = "..."` an optional operation id for this operation. Default is generated following `Spec.operation_id_strategy`.
 ///   - `summary = "..."` an optional summary
 ///   - `description = "..."` an optional description
-///   - `tag = "..."` an optional list of tags associated to this operation (define tag multiple time to add to the list)
+///   - `tag = "..."` an optional list of tags associated to this operation (define tag multiple time to add to the list).
+///  A variant of an enum deriving [ApiTags](derive.ApiTags.html) can be used instead of a string, e.g. `tag = Tags::Pet`
 ///   - `security_scope(...)` an optional list representing which security scopes apply for a given operation with
 ///       - `name = "..."` a mandatory name referencing one of the security definition
-///       - `scope(...)` a list of scope applying to this operation
-///   - `error_code = 00` an optional list of error code to document only theses
-///   - `consumes = "..."` allow to override body content type
-///   - `produces = "..."` allow to override response content type
+///       - `scope(...)` a list of scope applying to this operation. A variant of an enum deriving [ApiScopes](derive.ApiScopes.html) can be used instead of a string, e.g. `scope = Scopes::ReadPets`
+///
+///     Undeclared security schemes and scopes are logged as warnings when building the app, or rejected using `Spec::strict_security`.
+///   - `security = none` document the operation as not requiring any security, securities declared on enclosing scopes are not applied
+///   - `error_code = 00` an optional list of error code to document only theses. Failures of the extractors used as
+///     arguments (e.g. `415` for a `Json` payload) are documented alongside the handler errors and filtered the same way
+///   - `consumes = "..."` allow to override body content type. A list can be provided instead to document several
+///     content types, e.g. `consumes("application/json", "application/merge-patch+json")`
+///   - `produces = "..."` allow to override response content type. A list can be provided as well, e.g.
+///     `produces("application/json", "application/vnd.company.v2+json")`
+///
+///   Within `consumes(...)` and `produces(...)` lists, `media_type(content_type = "...", schema = Type)` documents
+///   `Type` (deriving [ApiComponent](derive.ApiComponent.html)) for this content type instead of the inferred schema.
+///   - `response(...)` an optional list of responses (define `response` multiple time to add to the list), useful for
+///     handlers returning `HttpResponse` or `impl Responder`. Declared responses replace the successful responses
+///     inferred from the handler return type, errors are still documented from the handler error type.
+///       - `status = 000` a **required** http status code, from 100 to 599
+///       - `body = Type` an optional type deriving [ApiComponent](derive.ApiComponent.html) documented as body
+///       - `content_type = "..."` an optional content type for the body, default to the body type content type
+///       - `description = "..."` an optional description, default is the canonical reason of the given status code
+///       - `headers(...)` an optional list of types deriving [ApiHeader](derive.ApiHeader.html) documented as response headers
 ///
 /// If `summary` or `description` are not provided, default value will be extracted from comments. The first line is used as summary while the rest will be part of the description.
 ///
@@ -513,7 +941,7 @@ pub fn derive_api_error(input: TokenStream) -> TokenStream {
 /// use actix_web::http::StatusCode;
 /// use actix_web::ResponseError;
 /// use core::fmt::Formatter;
-/// use apistos::actix::CreatedJson;
+/// use apistos::actix::{CreatedJson, StatusJson};
 /// use apistos::{api_operation, ApiComponent, ApiErrorComponent};
 /// use schemars::JsonSchema;
 /// use serde::{Serialize, Deserialize};
@@ -552,7 +980,7 @@ pub fn derive_api_error(input: TokenStream) -> TokenStream {
 /// pub(crate) async fn test(
 ///   body: Json<Test>,
 /// ) -> Result<CreatedJson<Test>, ErrorResponse> {
-///   Ok(CreatedJson(body.0))
+///   Ok(StatusJson(body.0))
 /// }
 /// ```
 ///
@@ -563,7 +991,7 @@ pub fn derive_api_error(input: TokenStream) -> TokenStream {
 /// use actix_web::http::StatusCode;
 /// use actix_web::ResponseError;
 /// use core::fmt::Formatter;
-/// use apistos::actix::CreatedJson;
+/// use apistos::actix::{CreatedJson, StatusJson};
 /// use apistos::{api_operation, ApiComponent, ApiErrorComponent};
 /// use schemars::JsonSchema;
 /// use serde::{Serialize, Deserialize};
@@ -602,7 +1030,7 @@ pub fn derive_api_error(input: TokenStream) -> TokenStream {
 /// pub(crate) async fn test(
 ///   body: Json<Test>,
 /// ) -> Result<CreatedJson<Test>, ErrorResponse> {
-///   Ok(CreatedJson(body.0))
+///   Ok(StatusJson(body.0))
 /// }
 /// ```
 #[proc_macro_error]
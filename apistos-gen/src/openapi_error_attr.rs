@@ -30,7 +30,14 @@ pub(crate) struct OpenapiErrorAttribute {
 impl ToTokens for OpenapiErrorAttribute {
   fn to_tokens(&self, tokens: &mut TokenStream) {
     let defs = &self.status;
+    let headers = self.status.iter().flat_map(|def| &def.headers);
     tokens.extend(quote! {
+      fn header_components() -> Vec<(String, apistos::reference_or::ReferenceOr<apistos::paths::Header>)> {
+        std::iter::empty()
+          #(.chain(apistos::response_header_component::<#headers>()))*
+          .collect()
+      }
+
       fn error_responses() -> Vec<(String, apistos::paths::Response)> {
         let responses: Vec<((String, apistos::paths::Response), Option<(String, apistos::reference_or::ReferenceOr<apistos::Schema>)>)> = vec![#(#defs,)*];
         responses.into_iter().map(|v| v.0).collect()
@@ -53,6 +60,9 @@ impl ToTokens for OpenapiErrorAttribute {
 pub(crate) struct ErrorDefinition {
   pub(crate) code: u16,
   pub(crate) description: Option<String>,
+  /// Types deriving `ApiHeader` documented as response headers, e.g. `header = RetryAfter`
+  #[darling(multiple, rename = "header")]
+  pub(crate) headers: Vec<syn::Path>,
 }
 
 impl ToTokens for ErrorDefinition {
@@ -63,10 +73,17 @@ impl ToTokens for ErrorDefinition {
       Err(e) => abort!(Span::call_site(), format!("{e}")),
     };
     let description = self.description.as_deref().unwrap_or(default_description);
+    let headers = &self.headers;
     tokens.extend(quote! {
-      ((#code.to_string(), apistos::paths::Response {
-        description: #description.to_string(),
-        ..Default::default()
+      ((#code.to_string(), {
+        let mut response = apistos::paths::Response {
+          description: #description.to_string(),
+          ..Default::default()
+        };
+        #(
+          apistos::insert_response_header::<#headers>(&mut response);
+        )*
+        response
       }), None)
     });
   }
@@ -37,6 +37,8 @@ pub(crate) struct OpenapiHeaderAttribute {
   pub(crate) description: Option<String>,
   pub(crate) required: Option<bool>,
   pub(crate) deprecated: Option<bool>,
+  #[darling(default)]
+  pub(crate) component: bool,
 }
 
 impl ToTokens for OpenapiHeaderAttribute {
@@ -56,6 +58,7 @@ impl ToTokens for OpenapiHeaderAttribute {
     let required = quote!(#required);
     let deprecated = self.deprecated.unwrap_or_default();
     let deprecated = quote!(#deprecated);
+    let component = self.component;
 
     tokens.extend(quote! {
       fn name() -> String {
@@ -73,6 +76,10 @@ impl ToTokens for OpenapiHeaderAttribute {
       fn deprecated() -> bool {
         #deprecated
       }
+
+      fn component() -> bool {
+        #component
+      }
     })
   }
 }
@@ -0,0 +1,86 @@
+use actix_web::http::StatusCode;
+use darling::util::PathList;
+use darling::FromMeta;
+use proc_macro2::{Span, TokenStream};
+use proc_macro_error::abort;
+use quote::quote;
+use syn::Attribute;
+
+pub(crate) fn parse_openapi_response_attrs(attrs: &[Attribute]) -> Option<OpenapiResponseAttribute> {
+  let response_attribute = attrs
+    .iter()
+    .filter(|attribute| attribute.path().is_ident("openapi_response"))
+    .map(|attribute| OpenapiResponseAttribute::from_meta(&attribute.meta))
+    .collect::<darling::Result<Vec<OpenapiResponseAttribute>>>();
+
+  match response_attribute {
+    Ok(response_attributes) if response_attributes.len() > 1 => {
+      abort!(Span::call_site(), "Expected only one #[openapi_response] attribute")
+    }
+    Ok(response_attributes) => response_attributes.first().cloned(),
+    Err(e) => abort!(e.span(), "Unable to parse #[openapi_response] attribute: {:?}", e),
+  }
+}
+
+#[derive(FromMeta, Clone)]
+pub(crate) struct OpenapiResponseAttribute {
+  pub(crate) status: u16,
+  pub(crate) description: Option<String>,
+  /// Types deriving `ApiHeader` documented as response headers
+  #[darling(default)]
+  pub(crate) headers: PathList,
+}
+
+/// Status code of a documented response, aborting for codes which aren't `1XX` to `5XX` http status codes.
+pub(crate) fn response_status_code(status: u16) -> StatusCode {
+  match StatusCode::from_u16(status) {
+    Ok(status_code) if status < 600 => status_code,
+    Ok(_) => abort!(
+      Span::call_site(),
+      format!("invalid status code `{status}`, expected 100 to 599")
+    ),
+    Err(e) => abort!(Span::call_site(), format!("{e}")),
+  }
+}
+
+/// Description of a documented response, defaulting to the canonical reason of its status code.
+pub(crate) fn response_description(status_code: StatusCode, description: Option<String>) -> String {
+  description.unwrap_or_else(|| status_code.canonical_reason().unwrap_or_default().to_string())
+}
+
+impl OpenapiResponseAttribute {
+  /// Documentation of the response, `body` being the type of the variant field if any.
+  pub(crate) fn response(&self, body: Option<&syn::Type>, doc_comments: &[String]) -> TokenStream {
+    let status_code = response_status_code(self.status);
+    let status = self.status.to_string();
+    let description = match (&self.description, doc_comments.is_empty()) {
+      (None, false) => doc_comments.join("\n"),
+      (description, _) => response_description(status_code, description.clone()),
+    };
+    let response = match body {
+      None => quote!(apistos::reference_or::ReferenceOr::Object(
+        apistos::paths::Response::default()
+      )),
+      Some(body) => quote! {
+        apistos::success_responses::<#body>(None)
+          .into_iter()
+          .next()
+          .map(|(_, response)| response)
+          .unwrap_or_else(|| apistos::reference_or::ReferenceOr::Object(apistos::paths::Response::default()))
+      },
+    };
+    let headers = self.headers.iter();
+    quote! {
+      {
+        let mut response = #response;
+        if let apistos::reference_or::ReferenceOr::Object(response) = &mut response {
+          response.description = #description.to_string();
+          #(
+            apistos::insert_response_header::<#headers>(response);
+          )*
+        }
+        apistos::insert_response(&mut responses, #status.to_string(), response);
+      }
+    }
+  }
+}
@@ -0,0 +1,26 @@
+use darling::FromMeta;
+use proc_macro2::Span;
+use proc_macro_error::abort;
+use syn::Attribute;
+
+pub(crate) fn parse_openapi_scope_attrs(attrs: &[Attribute]) -> Option<OpenapiScopeAttribute> {
+  let scope_attribute = attrs
+    .iter()
+    .filter(|attribute| attribute.path().is_ident("openapi_scope"))
+    .map(|attribute| OpenapiScopeAttribute::from_meta(&attribute.meta))
+    .collect::<darling::Result<Vec<OpenapiScopeAttribute>>>();
+
+  match scope_attribute {
+    Ok(scope_attributes) if scope_attributes.len() > 1 => {
+      abort!(Span::call_site(), "Expected only one #[openapi_scope] attribute")
+    }
+    Ok(scope_attributes) => scope_attributes.first().cloned(),
+    Err(e) => abort!(e.span(), "Unable to parse #[openapi_scope] attribute: {:?}", e),
+  }
+}
+
+#[derive(FromMeta, Clone, Default)]
+pub(crate) struct OpenapiScopeAttribute {
+  pub(crate) name: Option<String>,
+  pub(crate) description: Option<String>,
+}
@@ -1,9 +1,10 @@
 use crate::internal::security::models::{ApiKey, Http, OAuth2, OpenIdConnect};
+use darling::util::{Flag, PathList};
 use darling::FromMeta;
 use proc_macro2::{Span, TokenStream};
 use proc_macro_error::abort;
 use quote::{quote, ToTokens};
-use syn::Attribute;
+use syn::{Attribute, Generics, Ident};
 
 pub(crate) fn parse_openapi_security_attrs(attrs: &[Attribute], struct_name: String) -> Option<SecurityDeclaration> {
   let security_declarations_res = attrs
@@ -18,9 +19,25 @@ pub(crate) fn parse_openapi_security_attrs(attrs: &[Attribute], struct_name: Str
     }
     Ok(security_declarations) => {
       let security_declaration = security_declarations.first().cloned();
-      security_declaration.map(|s| SecurityDeclaration {
-        name: s.name.unwrap_or(struct_name),
-        scheme: s.scheme,
+      security_declaration.map(|s| match (s.scheme, s.all_of) {
+        (Some(scheme), None) => SecurityDeclaration::Scheme {
+          name: s.name.unwrap_or(struct_name),
+          scheme,
+          extractor: s.extractor.is_present(),
+        },
+        (None, Some(_)) if s.extractor.is_present() => abort!(
+          s.extractor.span(),
+          "#[openapi_security(extractor)] can't be used with `all_of(...)`"
+        ),
+        (None, Some(all_of)) if s.name.is_none() && !all_of.is_empty() => SecurityDeclaration::AllOf(all_of.to_vec()),
+        (None, Some(_)) => abort!(
+          Span::call_site(),
+          "#[openapi_security(all_of(...))] expects at least one security and no name"
+        ),
+        _ => abort!(
+          Span::call_site(),
+          "Expected either `scheme(...)` or `all_of(...)` in #[openapi_security] attribute"
+        ),
       })
     }
     Err(e) => abort!(e.span(), "Unable to parse #[openapi_security] attribute: {:?}", e),
@@ -30,27 +47,81 @@ pub(crate) fn parse_openapi_security_attrs(attrs: &[Attribute], struct_name: Str
 #[derive(FromMeta, Clone)]
 struct SecurityDeclarationInternal {
   name: Option<String>,
-  scheme: SecurityScheme,
+  scheme: Option<SecurityScheme>,
+  all_of: Option<PathList>,
+  extractor: Flag,
 }
 
-#[derive(FromMeta, Clone)]
-pub(crate) struct SecurityDeclaration {
-  pub(crate) name: String,
-  pub(crate) scheme: SecurityScheme,
+pub(crate) enum SecurityDeclaration {
+  /// A single security scheme, optionally enforced by a generated `FromRequest` implementation.
+  Scheme {
+    name: String,
+    scheme: SecurityScheme,
+    extractor: bool,
+  },
+  /// Securities which must all be satisfied, documented as a single security requirement.
+  AllOf(Vec<syn::Path>),
 }
 
-impl ToTokens for SecurityDeclaration {
-  fn to_tokens(&self, tokens: &mut TokenStream) {
-    let name = &self.name;
-    let scheme = &self.scheme;
-    tokens.extend(quote! {
-      std::collections::BTreeMap::from_iter(
-        vec![(
-          #name.to_string(),
-          #scheme
-        )]
-      )
-    })
+impl SecurityDeclaration {
+  pub(crate) fn securities(&self) -> TokenStream {
+    match self {
+      SecurityDeclaration::Scheme { name, scheme, .. } => quote! {
+        std::collections::BTreeMap::from_iter(
+          vec![(
+            #name.to_string(),
+            #scheme
+          )]
+        )
+      },
+      SecurityDeclaration::AllOf(securities) => quote! {
+        let mut securities = std::collections::BTreeMap::new();
+        #(
+          securities.append(&mut <#securities as apistos::ApiComponent>::securities());
+        )*
+        securities
+      },
+    }
+  }
+
+  pub(crate) fn security_requirement_names(&self) -> TokenStream {
+    match self {
+      SecurityDeclaration::Scheme { name, .. } => quote!(vec![#name.to_string()]),
+      SecurityDeclaration::AllOf(securities) => quote! {
+        let mut names = vec![];
+        #(
+          names.append(&mut <#securities as apistos::ApiComponent>::security_requirement_names());
+        )*
+        names
+      },
+    }
+  }
+
+  pub(crate) fn extractor_impl(&self, ident: &Ident, generics: &Generics) -> TokenStream {
+    match self {
+      SecurityDeclaration::Scheme { extractor: true, .. } => {
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        quote! {
+          #[automatically_derived]
+          impl #impl_generics ::actix_web::FromRequest for #ident #ty_generics #where_clause {
+            type Error = ::actix_web::Error;
+            type Future = apistos::ApiSecurityFuture<<Self as apistos::ApiSecurityValidator>::Future>;
+
+            fn from_request(req: &::actix_web::HttpRequest, _payload: &mut ::actix_web::dev::Payload) -> Self::Future {
+              apistos::validate_security::<Self>(req)
+            }
+          }
+        }
+      }
+      SecurityDeclaration::Scheme { .. } | SecurityDeclaration::AllOf(_) => quote!(),
+    }
+  }
+
+  pub(crate) fn security_requirement_name(&self) -> TokenStream {
+    match self {
+      SecurityDeclaration::Scheme { name, .. } => quote!(Some(#name.to_string())),
+      SecurityDeclaration::AllOf(_) => quote!(None),
+    }
   }
 }
 
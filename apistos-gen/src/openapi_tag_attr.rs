@@ -0,0 +1,51 @@
+use darling::FromMeta;
+use proc_macro2::{Span, TokenStream};
+use proc_macro_error::abort;
+use quote::{quote, ToTokens};
+use syn::Attribute;
+
+pub(crate) fn parse_openapi_tag_attrs(attrs: &[Attribute]) -> Option<OpenapiTagAttribute> {
+  let tag_attribute = attrs
+    .iter()
+    .filter(|attribute| attribute.path().is_ident("openapi_tag"))
+    .map(|attribute| OpenapiTagAttribute::from_meta(&attribute.meta))
+    .collect::<darling::Result<Vec<OpenapiTagAttribute>>>();
+
+  match tag_attribute {
+    Ok(tag_attributes) if tag_attributes.len() > 1 => {
+      abort!(Span::call_site(), "Expected only one #[openapi_tag] attribute")
+    }
+    Ok(tag_attributes) => tag_attributes.first().cloned(),
+    Err(e) => abort!(e.span(), "Unable to parse #[openapi_tag] attribute: {:?}", e),
+  }
+}
+
+#[derive(FromMeta, Clone, Default)]
+pub(crate) struct OpenapiTagAttribute {
+  pub(crate) name: Option<String>,
+  pub(crate) description: Option<String>,
+  pub(crate) external_docs: Option<ExternalDocsAttribute>,
+}
+
+#[derive(FromMeta, Clone)]
+pub(crate) struct ExternalDocsAttribute {
+  pub(crate) url: String,
+  pub(crate) description: Option<String>,
+}
+
+impl ToTokens for ExternalDocsAttribute {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let url = &self.url;
+    let description = match &self.description {
+      None => quote!(None),
+      Some(desc) => quote!(Some(#desc.to_string())),
+    };
+    tokens.extend(quote!(
+      apistos::paths::ExternalDocumentation {
+        description: #description,
+        url: #url.to_string(),
+        ..Default::default()
+      }
+    ))
+  }
+}
@@ -1,7 +1,12 @@
+use crate::openapi_response_attr::{response_description, response_status_code};
 use darling::ast::NestedMeta;
+use darling::util::PathList;
 use darling::FromMeta;
+use proc_macro2::TokenStream;
 use proc_macro_error::abort;
+use quote::{quote, ToTokens};
 use std::collections::BTreeMap;
+use syn::{Expr, ExprLit, Lit};
 
 pub(crate) fn parse_openapi_operation_attrs(attrs: &[NestedMeta]) -> OperationAttr {
   match OperationAttrInternal::from_list(attrs) {
@@ -20,20 +25,215 @@ struct OperationAttrInternal {
   summary: Option<String>,
   description: Option<String>,
   #[darling(multiple, rename = "tag")]
-  tags: Vec<String>,
+  tags: Vec<TypedName>,
   #[darling(multiple, rename = "security_scope")]
   scopes: Vec<SecurityScopes>,
+  security: Option<OperationSecurity>,
   #[darling(multiple, rename = "error_code")]
   error_codes: Vec<u16>,
-  consumes: Option<String>,
-  produces: Option<String>,
+  #[darling(default)]
+  consumes: MediaTypes,
+  #[darling(default)]
+  produces: MediaTypes,
+  #[darling(multiple, rename = "response")]
+  responses: Vec<ResponseDeclaration>,
+}
+
+/// Either a plain name (`tag = "pet"`) or a variant of an enum deriving `ApiTags` or `ApiScopes` (`tag = Tags::Pet`).
+#[derive(Clone)]
+pub(crate) enum TypedName {
+  Name(String),
+  Typed(syn::Path),
+}
+
+impl FromMeta for TypedName {
+  fn from_expr(expr: &Expr) -> darling::Result<Self> {
+    match expr {
+      Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) => Ok(TypedName::Name(s.value())),
+      Expr::Path(p) => Ok(TypedName::Typed(p.path.clone())),
+      _ => Err(darling::Error::unexpected_expr_type(expr)),
+    }
+  }
+}
+
+impl ToTokens for TypedName {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    match self {
+      TypedName::Name(name) => tokens.extend(quote!(#name.to_owned())),
+      TypedName::Typed(path) => tokens.extend(quote!(String::from(#path))),
+    }
+  }
+}
+
+/// `security = none` documents an operation as not requiring any security.
+#[derive(Clone)]
+pub(crate) enum OperationSecurity {
+  None,
+}
+
+impl FromMeta for OperationSecurity {
+  fn from_expr(expr: &Expr) -> darling::Result<Self> {
+    match expr {
+      Expr::Path(p) if p.path.is_ident("none") => Ok(OperationSecurity::None),
+      _ => Err(darling::Error::custom("expected `none`").with_span(expr)),
+    }
+  }
+}
+
+/// Media types declared with `consumes` or `produces`, either a single one (`consumes = "application/json"`) or a list
+/// (`consumes("application/json", media_type(content_type = "application/merge-patch+json", schema = PetPatch))`).
+#[derive(Clone, Default)]
+pub(crate) struct MediaTypes(pub(crate) Vec<MediaType>);
+
+#[derive(FromMeta, Clone)]
+pub(crate) struct MediaType {
+  content_type: String,
+  /// Type deriving `ApiComponent` documented for this media type instead of the one inferred from the handler
+  schema: Option<syn::Path>,
+}
+
+impl FromMeta for MediaTypes {
+  fn from_string(value: &str) -> darling::Result<Self> {
+    Ok(MediaTypes(vec![MediaType {
+      content_type: value.to_owned(),
+      schema: None,
+    }]))
+  }
+
+  fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+    if items.is_empty() {
+      return Err(darling::Error::too_few_items(1));
+    }
+    items
+      .iter()
+      .map(|item| match item {
+        NestedMeta::Lit(Lit::Str(content_type)) => Ok(MediaType {
+          content_type: content_type.value(),
+          schema: None,
+        }),
+        NestedMeta::Meta(meta) if meta.path().is_ident("media_type") => MediaType::from_meta(meta),
+        _ => Err(
+          darling::Error::custom(r#"expected `"..."` or `media_type(content_type = "...", schema = ...)`"#)
+            .with_span(item),
+        ),
+      })
+      .collect::<darling::Result<Vec<MediaType>>>()
+      .map(MediaTypes)
+  }
+}
+
+impl MediaTypes {
+  pub(crate) fn schemas(&self) -> Vec<&syn::Path> {
+    self
+      .0
+      .iter()
+      .filter_map(|media_type| media_type.schema.as_ref())
+      .collect()
+  }
+}
+
+impl ToTokens for MediaTypes {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let media_types = &self.0;
+    tokens.extend(quote! {
+      vec![#(#media_types,)*]
+    });
+  }
+}
+
+impl ToTokens for MediaType {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let content_type = &self.content_type;
+    let schema = match &self.schema {
+      None => quote!(None),
+      Some(schema) => schema_reference(schema),
+    };
+    tokens.extend(quote!((#content_type.to_string(), #schema)));
+  }
+}
+
+/// Response declared with `response(status = 200, body = Pet, description = "...", headers(Location))`, for handlers
+/// which responder doesn't document its responses (`HttpResponse` or `impl Responder` for example).
+#[derive(FromMeta, Clone)]
+pub(crate) struct ResponseDeclaration {
+  status: u16,
+  /// Type deriving `ApiComponent` documented as the response body
+  body: Option<syn::Path>,
+  description: Option<String>,
+  /// Content type of the body, default to the one of the body type
+  content_type: Option<String>,
+  /// Types deriving `ApiHeader` documented as response headers
+  #[darling(default)]
+  headers: PathList,
+}
+
+impl ResponseDeclaration {
+  pub(crate) fn schema(&self) -> Option<&syn::Path> {
+    self.body.as_ref()
+  }
+
+  pub(crate) fn headers(&self) -> impl Iterator<Item = &syn::Path> {
+    self.headers.iter()
+  }
+}
+
+impl ToTokens for ResponseDeclaration {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let status_code = response_status_code(self.status);
+    let status = self.status.to_string();
+    let description = response_description(status_code, self.description.clone());
+    let content = match &self.body {
+      None => quote!(),
+      Some(body) => {
+        let content_type = match &self.content_type {
+          Some(content_type) => quote!(#content_type.to_string()),
+          None => quote!(<#body as apistos::ApiComponent>::content_type()),
+        };
+        let schema = schema_reference(body);
+        quote! {
+          response.content.insert(
+            #content_type,
+            apistos::paths::MediaType {
+              schema: #schema,
+              ..Default::default()
+            },
+          );
+        }
+      }
+    };
+    let headers = self.headers.iter();
+    tokens.extend(quote! {
+      {
+        let mut response = apistos::paths::Response {
+          description: #description.to_string(),
+          ..Default::default()
+        };
+        #content
+        #(
+          apistos::insert_response_header::<#headers>(&mut response);
+        )*
+        (#status.to_string(), response)
+      }
+    });
+  }
+}
+
+/// Reference to the schema of a type deriving `ApiComponent`, falling back to its inlined schema.
+fn schema_reference(schema: &syn::Path) -> TokenStream {
+  quote! {
+    <#schema as apistos::ApiComponent>::schema()
+      .map(|(name, _)| apistos::reference_or::ReferenceOr::Reference {
+        _ref: format!("#/components/schemas/{}", name),
+      })
+      .or_else(<#schema as apistos::ApiComponent>::raw_schema)
+  }
 }
 
 #[derive(FromMeta, Clone)]
 struct SecurityScopes {
   name: String,
   #[darling(multiple, rename = "scope")]
-  scopes: Vec<String>,
+  scopes: Vec<TypedName>,
 }
 
 pub(crate) struct OperationAttr {
@@ -42,11 +242,13 @@ pub(crate) struct OperationAttr {
   pub(crate) operation_id: Option<String>,
   pub(crate) summary: Option<String>,
   pub(crate) description: Option<String>,
-  pub(crate) tags: Vec<String>,
-  pub(crate) scopes: BTreeMap<String, Vec<String>>,
+  pub(crate) tags: Vec<TypedName>,
+  pub(crate) scopes: BTreeMap<String, Vec<TypedName>>,
+  pub(crate) security_disabled: bool,
   pub(crate) error_codes: Vec<u16>,
-  pub(crate) consumes: Option<String>,
-  pub(crate) produces: Option<String>,
+  pub(crate) consumes: MediaTypes,
+  pub(crate) produces: MediaTypes,
+  pub(crate) responses: Vec<ResponseDeclaration>,
 }
 
 impl From<OperationAttrInternal> for OperationAttr {
@@ -63,9 +265,11 @@ impl From<OperationAttrInternal> for OperationAttr {
         .into_iter()
         .map(|s| (s.name, s.scopes))
         .collect::<BTreeMap<_, _>>(),
+      security_disabled: matches!(value.security, Some(OperationSecurity::None)),
       error_codes: value.error_codes,
       consumes: value.consumes,
       produces: value.produces,
+      responses: value.responses,
     }
   }
 }
@@ -1,31 +1,56 @@
-use crate::ApiComponent;
-use actix_web::body::BoxBody;
+use crate::{ApiComponent, ApiHeader};
+use actix_web::body::{BoxBody, EitherBody};
+use actix_web::error::HttpError;
+use actix_web::http::header::{HeaderName, HeaderValue, InvalidHeaderValue, TryIntoHeaderValue, CONTENT_TYPE};
 use actix_web::http::StatusCode;
-use actix_web::{HttpRequest, HttpResponse, Responder, ResponseError};
-use apistos_models::paths::{MediaType, RequestBody, Response, Responses};
+use actix_web::{CustomizeResponder, HttpRequest, HttpResponse, Responder, ResponseError};
+use apistos_core::parameters::header::{insert_response_header, response_header_component};
+use apistos_core::success_responses;
+use apistos_models::paths::{Header, MediaType, RequestBody, Response, Responses};
 use apistos_models::reference_or::ReferenceOr;
-use apistos_models::Schema;
+use apistos_models::{InstanceType, Schema, SchemaObject};
 use serde::Serialize;
 use std::collections::BTreeMap;
 use std::fmt::Debug;
+use std::marker::PhantomData;
 
 pub use apistos_core::{ResponderWrapper, ResponseWrapper};
 
-/// Empty struct to represent a 204 empty response
+/// Status code `CODE`, checked at compile time.
+struct ValidStatus<const CODE: u16>;
+
+impl<const CODE: u16> ValidStatus<CODE> {
+  const VALID: () = assert!(CODE >= 100 && CODE < 1000, "Invalid status code");
+
+  fn status() -> StatusCode {
+    #[allow(clippy::let_unit_value)]
+    let () = Self::VALID;
+    StatusCode::from_u16(CODE).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+  }
+}
+
+/// Empty response answered with the `CODE` status, e.g. `Status::<205>`
 #[derive(Debug)]
-pub struct NoContent;
+pub struct Status<const CODE: u16>;
 
-impl Responder for NoContent {
+impl<const CODE: u16> Status<CODE> {
+  /// Status answered, `CODE` as a `StatusCode`
+  pub fn status() -> StatusCode {
+    ValidStatus::<CODE>::status()
+  }
+}
+
+impl<const CODE: u16> Responder for Status<CODE> {
   type Body = BoxBody;
 
   fn respond_to(self, _req: &HttpRequest) -> HttpResponse<Self::Body> {
-    HttpResponse::build(StatusCode::NO_CONTENT)
+    HttpResponse::build(Self::status())
       .content_type("application/json")
       .finish()
   }
 }
 
-impl ApiComponent for NoContent {
+impl<const CODE: u16> ApiComponent for Status<CODE> {
   fn child_schemas() -> Vec<(String, ReferenceOr<Schema>)> {
     vec![]
   }
@@ -35,10 +60,9 @@ impl ApiComponent for NoContent {
   }
 
   fn responses(_content_type: Option<String>) -> Option<Responses> {
-    let status = StatusCode::NO_CONTENT;
     Some(Responses {
       responses: BTreeMap::from_iter(vec![(
-        status.as_str().to_string(),
+        Self::status().as_str().to_string(),
         ReferenceOr::Object(Response::default()),
       )]),
       ..Default::default()
@@ -46,27 +70,81 @@ impl ApiComponent for NoContent {
   }
 }
 
-/// Empty struct to represent a 202 with a body
-pub struct AcceptedJson<T: Serialize + ApiComponent>(pub T);
+/// Empty struct to represent a 204 empty response, equivalent to `Status<204>`
+#[derive(Debug)]
+pub struct NoContent;
+
+impl Responder for NoContent {
+  type Body = BoxBody;
+
+  fn respond_to(self, req: &HttpRequest) -> HttpResponse<Self::Body> {
+    Status::<204>.respond_to(req)
+  }
+}
+
+impl ApiComponent for NoContent {
+  fn child_schemas() -> Vec<(String, ReferenceOr<Schema>)> {
+    vec![]
+  }
+
+  fn schema() -> Option<(String, ReferenceOr<Schema>)> {
+    None
+  }
+
+  fn responses(content_type: Option<String>) -> Option<Responses> {
+    Status::<204>::responses(content_type)
+  }
+}
+
+/// Json body answered with the `CODE` status, e.g. `StatusJson::<206, _>(page)`
+///
+/// The body is answered as `application/json` unless changed using [`StatusJson::with_content_type`]. The documented
+/// content type can be changed using `produces` on `#[api_operation]`.
+pub struct StatusJson<const CODE: u16, T: Serialize + ApiComponent>(pub T);
+
+impl<const CODE: u16, T> StatusJson<CODE, T>
+where
+  T: Serialize + ApiComponent,
+{
+  /// Status answered, `CODE` as a `StatusCode`
+  pub fn status() -> StatusCode {
+    ValidStatus::<CODE>::status()
+  }
+
+  /// Unwrap the answered body
+  pub fn into_inner(self) -> T {
+    self.0
+  }
+
+  /// Answer the body with the given content type, e.g. `application/vnd.company.v2+json`
+  pub fn with_content_type<V>(self, content_type: V) -> CustomizeResponder<Self>
+  where
+    V: TryIntoHeaderValue,
+    V::Error: Into<InvalidHeaderValue>,
+  {
+    self.customize().insert_header((CONTENT_TYPE, content_type))
+  }
+}
 
-impl<T> Responder for AcceptedJson<T>
+impl<const CODE: u16, T> Responder for StatusJson<CODE, T>
 where
   T: Serialize + ApiComponent,
 {
   type Body = BoxBody;
 
   fn respond_to(self, _req: &HttpRequest) -> HttpResponse<Self::Body> {
-    let status = StatusCode::ACCEPTED;
     let body = match serde_json::to_string(&self.0) {
       Ok(body) => body,
       Err(e) => return e.error_response(),
     };
 
-    HttpResponse::build(status).content_type("application/json").body(body)
+    HttpResponse::build(Self::status())
+      .content_type("application/json")
+      .body(body)
   }
 }
 
-impl<T> ApiComponent for AcceptedJson<T>
+impl<const CODE: u16, T> ApiComponent for StatusJson<CODE, T>
 where
   T: Serialize + ApiComponent,
 {
@@ -86,55 +164,309 @@ where
     None
   }
 
-  fn responses(_content_type: Option<String>) -> Option<Responses> {
-    let status = StatusCode::ACCEPTED;
-    response_from_schema(status, Self::schema())
+  fn responses(content_type: Option<String>) -> Option<Responses> {
+    response_from_schema(Self::status(), Self::schema(), content_type)
   }
 }
 
-/// Empty struct to represent a 201 with a body
-pub struct CreatedJson<T: Serialize + ApiComponent>(pub T);
+/// Json body answered with a 202 status, built with `StatusJson(value)`
+pub type AcceptedJson<T> = StatusJson<202, T>;
+
+/// Json body answered with a 201 status, built with `StatusJson(value)`
+pub type CreatedJson<T> = StatusJson<201, T>;
 
-impl<T> Responder for CreatedJson<T>
+impl<T> StatusJson<201, T>
 where
   T: Serialize + ApiComponent,
 {
-  type Body = BoxBody;
+  /// Answer the url of the created resource as `Location` header, documented as such.
+  pub fn with_location<V>(self, location: V) -> WithHeader<Self, Location>
+  where
+    V: TryIntoHeaderValue,
+    V::Error: Into<HttpError>,
+  {
+    self.with_typed_header(location)
+  }
+}
 
-  fn respond_to(self, _req: &HttpRequest) -> HttpResponse<Self::Body> {
-    let status = StatusCode::CREATED;
-    let body = match serde_json::to_string(&self.0) {
-      Ok(body) => body,
-      Err(e) => return e.error_response(),
-    };
+/// Responder answering `R` with the `CODE` status, documented as such.
+///
+/// Statuses set at runtime can't be documented. `(R, StatusCode)` doesn't implement `ApiComponent` for this reason, and
+/// `R::customize().with_status(...)` is documented as `R` documents it, which doesn't match the answered status. Use
+/// `WithStatus` or `StatusJson` instead.
+///
+/// ```rust
+/// use actix_web::web::Json;
+/// use apistos::actix::{ApiResponder, WithStatus};
+/// use apistos::ApiComponent;
+/// use schemars::JsonSchema;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize, JsonSchema, ApiComponent)]
+/// pub struct Pet {
+///   pub name: String,
+/// }
+///
+/// pub async fn create_pet() -> WithStatus<201, Json<Pet>> {
+///   Json(Pet { name: "Rex".to_string() }).with_typed_status()
+/// }
+/// ```
+pub struct WithStatus<const CODE: u16, R>(pub R);
+
+impl<const CODE: u16, R> WithStatus<CODE, R> {
+  /// Status answered, `CODE` as a `StatusCode`
+  pub fn status() -> StatusCode {
+    ValidStatus::<CODE>::status()
+  }
 
-    HttpResponse::build(status).content_type("application/json").body(body)
+  /// Unwrap the wrapped responder
+  pub fn into_inner(self) -> R {
+    self.0
   }
 }
 
-impl<T> ApiComponent for CreatedJson<T>
+impl<const CODE: u16, R> Responder for WithStatus<CODE, R>
 where
-  T: Serialize + ApiComponent,
+  R: Responder,
+{
+  type Body = R::Body;
+
+  fn respond_to(self, req: &HttpRequest) -> HttpResponse<Self::Body> {
+    let mut response = self.0.respond_to(req);
+    // failures keep their own status
+    if response.error().is_none() {
+      *response.status_mut() = Self::status();
+    }
+    response
+  }
+}
+
+impl<const CODE: u16, R> ApiComponent for WithStatus<CODE, R>
+where
+  R: ApiComponent,
 {
+  fn content_type() -> String {
+    R::content_type()
+  }
+
   fn child_schemas() -> Vec<(String, ReferenceOr<Schema>)> {
-    T::child_schemas()
+    R::child_schemas()
   }
 
   fn raw_schema() -> Option<ReferenceOr<Schema>> {
-    T::raw_schema()
+    R::raw_schema()
   }
 
   fn schema() -> Option<(String, ReferenceOr<Schema>)> {
-    T::schema()
+    R::schema()
   }
 
-  fn responses(_content_type: Option<String>) -> Option<Responses> {
-    let status = StatusCode::CREATED;
-    response_from_schema(status, Self::schema())
+  fn request_body() -> Option<RequestBody> {
+    None
+  }
+
+  fn error_responses() -> Vec<(String, Response)> {
+    R::error_responses()
+  }
+
+  fn error_schemas() -> BTreeMap<String, (String, ReferenceOr<Schema>)> {
+    R::error_schemas()
+  }
+
+  fn header_components() -> Vec<(String, ReferenceOr<Header>)> {
+    R::header_components()
+  }
+
+  fn responses(content_type: Option<String>) -> Option<Responses> {
+    let response = success_responses::<R>(content_type)
+      .into_iter()
+      .find(|(status, _)| status.parse::<u16>().map_or(false, |status| status < 400))
+      .map(|(_, response)| response)
+      .unwrap_or_else(|| ReferenceOr::Object(Response::default()));
+    Some(Responses {
+      responses: BTreeMap::from_iter(vec![(Self::status().as_str().to_string(), response)]),
+      ..Default::default()
+    })
+  }
+}
+
+/// Responder answering `R` with the `H` header, documented on every successful response of `R`.
+///
+/// ```rust
+/// use actix_web::web::Json;
+/// use apistos::actix::{ApiResponder, WithHeader};
+/// use apistos::{ApiComponent, ApiHeader};
+/// use schemars::JsonSchema;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize, JsonSchema, ApiComponent)]
+/// pub struct Pet {
+///   pub name: String,
+/// }
+///
+/// #[derive(JsonSchema, ApiHeader)]
+/// #[openapi_header(name = "ETag", description = "Version of the pet", required = true)]
+/// pub struct ETag(String);
+///
+/// pub async fn get_pet() -> WithHeader<Json<Pet>, ETag> {
+///   Json(Pet { name: "Rex".to_string() }).with_typed_header("\"1\"")
+/// }
+/// ```
+pub struct WithHeader<R, H> {
+  responder: R,
+  value: Result<HeaderValue, HttpError>,
+  header: PhantomData<H>,
+}
+
+impl<R, H> WithHeader<R, H> {
+  pub fn into_inner(self) -> R {
+    self.responder
+  }
+}
+
+impl<R, H> Responder for WithHeader<R, H>
+where
+  R: Responder,
+  H: ApiHeader,
+{
+  type Body = EitherBody<R::Body>;
+
+  fn respond_to(self, req: &HttpRequest) -> HttpResponse<Self::Body> {
+    let header = HeaderName::try_from(H::name())
+      .map_err(HttpError::from)
+      .and_then(|name| self.value.map(|value| (name, value)));
+    match header {
+      Ok((name, value)) => {
+        let mut response = self.responder.respond_to(req);
+        response.headers_mut().insert(name, value);
+        response.map_into_left_body()
+      }
+      Err(e) => HttpResponse::from_error(e).map_into_right_body(),
+    }
   }
 }
 
-fn response_from_schema(status: StatusCode, schema: Option<(String, ReferenceOr<Schema>)>) -> Option<Responses> {
+impl<R, H> ApiComponent for WithHeader<R, H>
+where
+  R: ApiComponent,
+  H: ApiComponent + ApiHeader,
+{
+  fn content_type() -> String {
+    R::content_type()
+  }
+
+  fn child_schemas() -> Vec<(String, ReferenceOr<Schema>)> {
+    R::child_schemas()
+  }
+
+  fn raw_schema() -> Option<ReferenceOr<Schema>> {
+    R::raw_schema()
+  }
+
+  fn schema() -> Option<(String, ReferenceOr<Schema>)> {
+    R::schema()
+  }
+
+  fn request_body() -> Option<RequestBody> {
+    None
+  }
+
+  fn error_responses() -> Vec<(String, Response)> {
+    R::error_responses()
+  }
+
+  fn error_schemas() -> BTreeMap<String, (String, ReferenceOr<Schema>)> {
+    R::error_schemas()
+  }
+
+  fn header_components() -> Vec<(String, ReferenceOr<Header>)> {
+    let mut headers = R::header_components();
+    headers.extend(response_header_component::<H>());
+    headers
+  }
+
+  fn responses(content_type: Option<String>) -> Option<Responses> {
+    let responses = success_responses::<R>(content_type)
+      .into_iter()
+      .map(|(status, mut response)| {
+        let success = status.parse::<u16>().map_or(false, |status| status < 400);
+        if let (true, ReferenceOr::Object(response)) = (success, &mut response) {
+          insert_response_header::<H>(response);
+        }
+        (status, response)
+      })
+      .collect();
+    Some(Responses {
+      responses,
+      ..Default::default()
+    })
+  }
+}
+
+/// `Location` response header, the url of a created resource.
+pub struct Location;
+
+impl ApiHeader for Location {
+  fn name() -> String {
+    "Location".to_string()
+  }
+
+  fn description() -> Option<String> {
+    Some("Url of the created resource".to_string())
+  }
+
+  fn required() -> bool {
+    true
+  }
+}
+
+impl ApiComponent for Location {
+  fn child_schemas() -> Vec<(String, ReferenceOr<Schema>)> {
+    vec![]
+  }
+
+  fn raw_schema() -> Option<ReferenceOr<Schema>> {
+    Some(ReferenceOr::Object(Schema::Object(SchemaObject {
+      instance_type: Some(InstanceType::String.into()),
+      format: Some("uri-reference".to_string()),
+      ..Default::default()
+    })))
+  }
+
+  fn schema() -> Option<(String, ReferenceOr<Schema>)> {
+    None
+  }
+}
+
+/// Typed status and header helpers, documenting the status and headers a responder answers with.
+pub trait ApiResponder: Responder + Sized {
+  /// Answer with the `CODE` status, e.g. `Json(pet).with_typed_status::<201>()`.
+  fn with_typed_status<const CODE: u16>(self) -> WithStatus<CODE, Self> {
+    WithStatus(self)
+  }
+
+  /// Answer with the `H` header, e.g. `Json(pet).with_typed_header::<ETag, _>(etag)`.
+  fn with_typed_header<H, V>(self, value: V) -> WithHeader<Self, H>
+  where
+    H: ApiHeader,
+    V: TryIntoHeaderValue,
+    V::Error: Into<HttpError>,
+  {
+    WithHeader {
+      responder: self,
+      value: value.try_into_value().map_err(Into::into),
+      header: PhantomData,
+    }
+  }
+}
+
+impl<R: Responder> ApiResponder for R {}
+
+fn response_from_schema(
+  status: StatusCode,
+  schema: Option<(String, ReferenceOr<Schema>)>,
+  content_type: Option<String>,
+) -> Option<Responses> {
   schema.map(|(name, schema)| match schema {
     ReferenceOr::Reference { _ref } => Responses {
       responses: BTreeMap::from_iter(vec![(status.as_str().to_string(), ReferenceOr::Reference { _ref })]),
@@ -143,7 +475,7 @@ fn response_from_schema(status: StatusCode, schema: Option<(String, ReferenceOr<
     ReferenceOr::Object(_) => {
       let response = Response {
         content: BTreeMap::from_iter(vec![(
-          "application/json".to_string(),
+          content_type.unwrap_or_else(|| "application/json".to_string()),
           MediaType {
             schema: Some(ReferenceOr::Reference {
               _ref: format!("#/components/schemas/{}", name),
@@ -166,7 +498,12 @@ mod test {
   #![allow(clippy::expect_used)]
 
   use crate as apistos;
-  use crate::actix::{AcceptedJson, CreatedJson, NoContent};
+  use crate::actix::{
+    AcceptedJson, ApiResponder, CreatedJson, Location, NoContent, Status, StatusJson, WithHeader, WithStatus,
+  };
+  use actix_web::test::TestRequest;
+  use actix_web::web::Json;
+  use actix_web::{Either, Responder};
   use apistos_core::ApiComponent;
   use apistos_gen::ApiComponent;
   use apistos_models::paths::Response;
@@ -216,4 +553,111 @@ mod test {
     let accepted_json_response = responses.responses.get("201");
     assert!(accepted_json_response.is_some());
   }
+
+  #[test]
+  fn created_json_with_location_generate_valid_response() {
+    #[derive(Serialize, ApiComponent, JsonSchema)]
+    struct Test {
+      test: String,
+    }
+
+    let responses =
+      <WithHeader<CreatedJson<Test>, Location> as ApiComponent>::responses(None).expect("missing responses");
+    let created_response = responses.responses.get("201");
+    assert!(matches!(created_response, Some(ReferenceOr::Object(obj)) if obj.headers.contains_key("Location")));
+
+    let req = TestRequest::default().to_http_request();
+    let response = StatusJson::<201, _>(Test {
+      test: "test".to_string(),
+    })
+    .with_location("/tests/1")
+    .respond_to(&req);
+    assert_eq!(response.status().as_u16(), 201);
+    assert_eq!(
+      response
+        .headers()
+        .get("location")
+        .and_then(|location| location.to_str().ok()),
+      Some("/tests/1")
+    );
+
+    let response = NoContent.with_typed_header::<Location, _>("\n").respond_to(&req);
+    assert_eq!(response.status().as_u16(), 500);
+  }
+
+  #[test]
+  fn with_status_generate_valid_response() {
+    #[derive(Serialize, ApiComponent, JsonSchema)]
+    struct Test {
+      test: String,
+    }
+
+    let responses = <WithStatus<201, Json<Test>> as ApiComponent>::responses(None).expect("missing responses");
+    assert_eq!(responses.responses.keys().collect::<Vec<&String>>(), vec!["201"]);
+
+    let req = TestRequest::default().to_http_request();
+    let response = Json(Test {
+      test: "test".to_string(),
+    })
+    .with_typed_status::<201>()
+    .respond_to(&req);
+    assert_eq!(response.status().as_u16(), 201);
+  }
+
+  #[test]
+  fn either_generate_valid_responses() {
+    #[derive(Serialize, ApiComponent, JsonSchema)]
+    struct Test {
+      test: String,
+    }
+
+    let responses = <Either<Json<Test>, NoContent> as ApiComponent>::responses(None).expect("missing responses");
+    assert_eq!(responses.responses.keys().collect::<Vec<&String>>(), vec!["200", "204"]);
+  }
+
+  #[test]
+  fn status_json_generate_valid_response() {
+    #[derive(Serialize, ApiComponent, JsonSchema)]
+    struct Test {
+      test: String,
+    }
+
+    let responses = <StatusJson<206, Test> as ApiComponent>::responses(None).expect("missing responses");
+    let partial_content_response = responses.responses.get("206");
+    assert!(
+      matches!(partial_content_response, Some(ReferenceOr::Object(obj)) if obj.content.contains_key("application/json"))
+    );
+
+    let responses =
+      <StatusJson<206, Test> as ApiComponent>::responses(Some("application/vnd.company.v2+json".to_string()))
+        .expect("missing responses");
+    let partial_content_response = responses.responses.get("206");
+    assert!(
+      matches!(partial_content_response, Some(ReferenceOr::Object(obj)) if obj.content.contains_key("application/vnd.company.v2+json"))
+    );
+
+    let req = TestRequest::default().to_http_request();
+    let response = StatusJson::<207, _>(Test {
+      test: "test".to_string(),
+    })
+    .with_content_type("application/vnd.company.v2+json")
+    .respond_to(&req);
+    assert_eq!(response.status().as_u16(), 207);
+    assert_eq!(
+      response
+        .headers()
+        .get("content-type")
+        .and_then(|content_type| content_type.to_str().ok()),
+      Some("application/vnd.company.v2+json")
+    );
+  }
+
+  #[test]
+  fn status_generate_valid_response() {
+    let responses = <Status<205> as ApiComponent>::responses(None).expect("missing responses");
+    assert_eq!(responses.responses.keys().collect::<Vec<&String>>(), vec!["205"]);
+
+    let req = TestRequest::default().to_http_request();
+    assert_eq!(Status::<205>.respond_to(&req).status().as_u16(), 205);
+  }
 }
@@ -1,24 +1,33 @@
 use crate::internal::actix::handler::OASHandler;
 use crate::internal::actix::route::{Route, RouteWrapper};
-use crate::internal::definition_holder::DefinitionHolder;
-use crate::spec::{DefaultParameters, Spec};
+use crate::internal::actix::utils::{openapi_path, OperationMetadata, OperationUpdater};
+use crate::internal::definition_holder::{merge_path_item, DefinitionHolder, DocumentedPathItem};
+use crate::middleware::{self, DocumentedMiddleware, DocumentedTransform};
+use crate::spec::{self, DefaultParameters, ExtractorErrors, OperationIdContext, OperationIdStrategy, Spec, TagGroup};
 use crate::web::ServiceConfig;
-use actix_service::{IntoServiceFactory, ServiceFactory, Transform};
+use actix_service::{IntoServiceFactory, Service, ServiceFactory, Transform};
 use actix_web::body::MessageBody;
 use actix_web::dev::{HttpServiceFactory, ServiceRequest, ServiceResponse};
 use actix_web::web::{get, resource};
 use actix_web::Error;
-use apistos_models::paths::{OperationType, Parameter};
+use apistos_models::components::Components;
+use apistos_models::paths::{OperationType, Parameter, ParameterIn, PathItem};
 use apistos_models::reference_or::ReferenceOr;
+use apistos_models::security::{SecurityScheme, SecurityType};
 use apistos_models::OpenApi;
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
+use log::warn;
 use once_cell::sync::Lazy;
 use regex::Regex;
-use schemars::schema::Schema;
-use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::collections::HashSet;
+use std::fmt;
 use std::future::Future;
+use std::rc::Rc;
 use std::sync::{Arc, RwLock};
-use std::{fmt, mem};
+
+/// Crate private metadata of every documented operation, by path and operation type.
+type OperationsMetadata = IndexMap<String, IndexMap<OperationType, OperationMetadata>>;
 
 pub trait OpenApiWrapper<T> {
   type Wrapper;
@@ -29,9 +38,16 @@ pub trait OpenApiWrapper<T> {
 /// Wrapper for [actix_web::App](https://docs.rs/actix-web/latest/actix_web/struct.App.html) with openapi specification
 pub struct App<T> {
   open_api_spec: Arc<RwLock<OpenApi>>,
+  operations_metadata: OperationsMetadata,
   inner: Option<actix_web::App<T>>, //an option juste to be able to replace it with a default in memory
   default_tags: Vec<String>,
   default_parameters: Vec<DefaultParameters>,
+  extractor_errors: ExtractorErrors,
+  transforms: Vec<Rc<dyn DocumentedTransform>>,
+  operation_id_strategy: OperationIdStrategy,
+  tag_groups: Vec<TagGroup>,
+  tags_from_scopes: bool,
+  strict_security: bool,
 }
 
 impl<T> OpenApiWrapper<T> for actix_web::App<T> {
@@ -49,11 +65,31 @@ impl<T> OpenApiWrapper<T> for actix_web::App<T> {
     if !spec.servers.is_empty() {
       open_api_spec.servers = spec.servers;
     }
+    if !spec.security.is_empty() {
+      let security_schemes = spec
+        .security
+        .iter()
+        .flat_map(|s| s.security_schemes.clone())
+        .map(|(name, security)| (name, ReferenceOr::Object(security)))
+        .collect();
+      open_api_spec.components = Some(Components {
+        security_schemes,
+        ..Default::default()
+      });
+      open_api_spec.security = spec.security.into_iter().map(|s| s.requirement).collect();
+    }
     App {
       open_api_spec: Arc::new(RwLock::new(open_api_spec)),
+      operations_metadata: Default::default(),
       inner: Some(self),
       default_tags: spec.default_tags,
       default_parameters: spec.default_parameters,
+      extractor_errors: spec.extractor_errors,
+      transforms: Default::default(),
+      operation_id_strategy: spec.operation_id_strategy,
+      tag_groups: spec.tag_groups,
+      tags_from_scopes: spec.tags_from_scopes,
+      strict_security: spec.strict_security,
     }
   }
 }
@@ -135,22 +171,54 @@ where
   }
 
   /// Drop in for [`actix_web::App::wrap`](https://docs.rs/actix-web/*/actix_web/struct.App.html#method.wrap)
+  ///
+  /// **NOTE:** Only middlewares wrapped in a [`DocumentedMiddleware`] affect spec generation, their effect being
+  /// documented on every operation of the app.
   pub fn wrap<M, B>(
     mut self,
     mw: M,
   ) -> App<impl ServiceFactory<ServiceRequest, Config = (), Response = ServiceResponse<B>, Error = Error, InitError = ()>>
   where
     M: Transform<T::Service, ServiceRequest, Response = ServiceResponse<B>, Error = Error, InitError = ()> + 'static,
-    B: MessageBody,
+    T::Service: 'static,
+    B: MessageBody + 'static,
   {
+    self
+      .transforms
+      .extend(DocumentedMiddleware::<T::Service, B>::transform_of(&mw));
     App {
       open_api_spec: self.open_api_spec,
+      operations_metadata: self.operations_metadata,
       inner: self.inner.take().map(|app| app.wrap(mw)),
       default_tags: self.default_tags,
       default_parameters: self.default_parameters,
+      extractor_errors: self.extractor_errors,
+      transforms: self.transforms,
+      operation_id_strategy: self.operation_id_strategy,
+      tag_groups: self.tag_groups,
+      tags_from_scopes: self.tags_from_scopes,
+      strict_security: self.strict_security,
     }
   }
 
+  /// Wrapper for [`actix_web::App::wrap`](https://docs.rs/actix-web/*/actix_web/struct.App.html#method.wrap) documenting the middleware effect on every operation of the app, shorthand for `wrap(DocumentedMiddleware::new(mw))`.
+  pub fn wrap_documented<M, B>(
+    self,
+    mw: M,
+  ) -> App<impl ServiceFactory<ServiceRequest, Config = (), Response = ServiceResponse<B>, Error = Error, InitError = ()>>
+  where
+    M: Transform<T::Service, ServiceRequest, Response = ServiceResponse<B>, Error = Error, InitError = ()>
+      + DocumentedTransform
+      + 'static,
+    M::Transform: 'static,
+    M::Future: 'static,
+    <M::Transform as Service<ServiceRequest>>::Future: 'static,
+    T::Service: 'static,
+    B: MessageBody + 'static,
+  {
+    self.wrap(DocumentedMiddleware::new(mw))
+  }
+
   /// Drop in for [`actix_web::App::wrap_fn`](https://docs.rs/actix-web/*/actix_web/struct.App.html#method.wrap_fn)
   pub fn wrap_fn<F, R, B>(
     mut self,
@@ -163,16 +231,102 @@ where
   {
     App {
       open_api_spec: self.open_api_spec,
+      operations_metadata: self.operations_metadata,
       inner: self.inner.take().map(|app| app.wrap_fn(mw)),
       default_tags: self.default_tags,
       default_parameters: self.default_parameters,
+      extractor_errors: self.extractor_errors,
+      transforms: self.transforms,
+      operation_id_strategy: self.operation_id_strategy,
+      tag_groups: self.tag_groups,
+      tags_from_scopes: self.tags_from_scopes,
+      strict_security: self.strict_security,
     }
   }
 
   /// Add a new resource at **`openapi_path`** to expose the generated openapi schema and return an [actix_web::App](https://docs.rs/actix-web/latest/actix_web/struct.App.html)
+  ///
+  /// Panics if `Spec::strict_security` is set and a security requirement references an undeclared security scheme or
+  /// oauth2 scope.
   #[allow(clippy::unwrap_used, clippy::expect_used)]
   pub fn build(self, openapi_path: &str) -> actix_web::App<T> {
-    let open_api_spec = self.open_api_spec.read().unwrap().clone();
+    let mut open_api_spec = self.open_api_spec.read().unwrap().clone();
+    if !self.transforms.is_empty() {
+      for path_item in open_api_spec.paths.paths.values_mut() {
+        middleware::update_path_item(&self.transforms, path_item);
+      }
+      merge_components(&mut open_api_spec.components, middleware::components(&self.transforms));
+    }
+    if !self.default_parameters.is_empty() {
+      for (path, path_item) in &mut open_api_spec.paths.paths {
+        spec::apply_default_parameters(&self.default_parameters, path, path_item);
+      }
+      merge_components(
+        &mut open_api_spec.components,
+        self
+          .default_parameters
+          .iter()
+          .map(DefaultParameters::to_components)
+          .collect(),
+      );
+    }
+    if !self.default_tags.is_empty() {
+      for op in open_api_spec
+        .paths
+        .paths
+        .values_mut()
+        .flat_map(|item| item.operations.values_mut())
+      {
+        for tag in &self.default_tags {
+          if !op.tags.contains(tag) {
+            op.tags.push(tag.clone());
+          }
+        }
+      }
+    }
+    let mut extractor_error_schema_used = false;
+    for (path, path_item) in &mut open_api_spec.paths.paths {
+      for (op_type, op) in &mut path_item.operations {
+        if let Some(metadata) = operation_metadata(&self.operations_metadata, path, op_type) {
+          if let Some(warning) = metadata.request_body_conflict_warning() {
+            warn!("{op_type:?} {path}: {warning}");
+          }
+          if metadata.security_disabled {
+            op.security = Some(vec![]);
+          }
+          extractor_error_schema_used |= self
+            .extractor_errors
+            .update_responses(op, &metadata.extractor_error_statuses);
+        }
+      }
+      hoist_common_parameters(path_item);
+    }
+    if extractor_error_schema_used {
+      merge_components(
+        &mut open_api_spec.components,
+        vec![self.extractor_errors.to_components()],
+      );
+    }
+    assign_tag_groups(
+      &mut open_api_spec,
+      &self.operations_metadata,
+      self.tag_groups,
+      self.tags_from_scopes,
+    );
+    let security_warnings = security_warnings(&open_api_spec);
+    assert!(
+      !self.strict_security || security_warnings.is_empty(),
+      "Invalid security requirements:\n{}",
+      security_warnings.join("\n")
+    );
+    for warning in security_warnings {
+      warn!("{warning}");
+    }
+    assign_operation_ids(
+      &mut open_api_spec.paths.paths,
+      &self.operations_metadata,
+      &self.operation_id_strategy,
+    );
     self
       .inner
       .expect("Missing app")
@@ -183,73 +337,346 @@ where
   #[allow(clippy::unwrap_used)]
   fn update_from_def_holder<D: DefinitionHolder>(&mut self, definition_holder: &mut D) {
     let mut open_api_spec = self.open_api_spec.write().unwrap();
-    let mut components = definition_holder.components().into_iter().reduce(|mut acc, component| {
-      acc.schemas.extend(component.schemas);
-      acc.responses.extend(component.responses);
-      acc.security_schemes.extend(component.security_schemes);
-      acc
-    });
-    definition_holder.update_path_items(&mut open_api_spec.paths.paths);
-    let mut paths = IndexMap::new();
-    for (path, mut item) in mem::take(&mut open_api_spec.paths.paths) {
+    let mut components = open_api_spec.components.take();
+    merge_components(&mut components, definition_holder.components());
+    let mut new_paths = IndexMap::new();
+    definition_holder.update_path_items(&mut new_paths);
+    for (path, DocumentedPathItem { mut item, metadata }) in new_paths {
+      // path parameters can only be validated once the full path is known
+      for (op_type, op) in &mut item.operations {
+        op.update_path_parameter_name_from_path(&path);
+        for warning in op.sort_path_parameters(&path) {
+          warn!("{op_type:?} {path}: {warning}");
+        }
+      }
+
+      let path = openapi_path(&path);
       let path = if path.starts_with('/') {
         path
       } else {
         "/".to_owned() + &path
       };
+      self
+        .operations_metadata
+        .entry(path.clone())
+        .or_default()
+        .extend(metadata);
+      merge_path_item(open_api_spec.paths.paths.entry(path).or_default(), item);
+    }
 
-      item.operations.iter_mut().for_each(|(op_type, op)| {
-        let operation_id = build_operation_id(&path, op_type);
-        op.operation_id = op.operation_id.clone().or(Some(operation_id));
-      });
+    open_api_spec.components = components;
+  }
+}
 
-      paths.insert(path, item);
+/// Merges the given components into the already collected ones.
+fn merge_components(target: &mut Option<Components>, components: Vec<Components>) {
+  for component in components {
+    match target {
+      Some(acc) => {
+        acc.schemas.extend(component.schemas);
+        acc.responses.extend(component.responses);
+        acc.parameters.extend(component.parameters);
+        acc.examples.extend(component.examples);
+        acc.request_bodies.extend(component.request_bodies);
+        for (name, header) in component.headers {
+          match acc.headers.entry(name) {
+            Entry::Vacant(entry) => {
+              entry.insert(header);
+            }
+            Entry::Occupied(entry) => {
+              if serde_json::to_value(entry.get()).ok() != serde_json::to_value(&header).ok() {
+                warn!(
+                  "Header component `{}` is documented with different definitions, the first one is kept",
+                  entry.key()
+                );
+              }
+            }
+          }
+        }
+        acc.security_schemes.extend(component.security_schemes);
+        acc.links.extend(component.links);
+        acc.callbacks.extend(component.callbacks);
+        acc.extensions.extend(component.extensions);
+      }
+      None => *target = Some(component),
     }
+  }
+}
 
-    if !self.default_parameters.is_empty() {
-      let mut parameter_components: BTreeMap<String, ReferenceOr<Parameter>> = self
-        .default_parameters
-        .iter()
-        .flat_map(|p| &p.parameters)
-        .map(|p| (p.name.clone(), ReferenceOr::Object(p.clone())))
-        .collect();
+/// Moves parameters shared by every operation of a path item, path parameters in particular, to the path item itself.
+fn hoist_common_parameters(path_item: &mut PathItem) {
+  if path_item.operations.len() < 2 {
+    return;
+  }
+  let mut operations = path_item.operations.values();
+  let Some(first) = operations.next() else {
+    return;
+  };
+  let others = operations.collect::<Vec<_>>();
+  let common_parameters = first
+    .parameters
+    .iter()
+    .filter(|parameter| {
+      let value = serde_json::to_value(parameter).ok();
+      others.iter().all(|op| {
+        op.parameters
+          .iter()
+          .any(|p| parameter_key(p) == parameter_key(parameter) && serde_json::to_value(p).ok() == value)
+      })
+    })
+    .cloned()
+    .collect::<Vec<_>>();
+  if common_parameters.is_empty() {
+    return;
+  }
 
-      let mut schema_components: BTreeMap<String, ReferenceOr<Schema>> = self
-        .default_parameters
-        .iter()
-        .flat_map(|p| p.components.clone())
-        .collect();
+  let common_keys = common_parameters.iter().map(parameter_key).collect::<Vec<_>>();
+  for op in path_item.operations.values_mut() {
+    op.parameters.retain(|p| !common_keys.contains(&parameter_key(p)));
+  }
+  for parameter in common_parameters {
+    let key = parameter_key(&parameter);
+    if !path_item.parameters.iter().any(|p| parameter_key(p) == key) {
+      path_item.parameters.push(parameter);
+    }
+  }
+}
+
+/// A parameter is identified by its name and location, or by its reference.
+fn parameter_key(parameter: &ReferenceOr<Parameter>) -> (String, Option<ParameterIn>) {
+  match parameter {
+    ReferenceOr::Reference { _ref } => (_ref.clone(), None),
+    ReferenceOr::Object(p) => (p.name.clone(), Some(p._in.clone())),
+  }
+}
 
-      let mut parameter_refs = self
-        .default_parameters
+/// Security requirements referencing undeclared security schemes or oauth2 scopes.
+fn security_warnings(open_api_spec: &OpenApi) -> Vec<String> {
+  let mut warnings = vec![];
+  let security_schemes = open_api_spec
+    .components
+    .as_ref()
+    .map(|c| &c.security_schemes)
+    .cloned()
+    .unwrap_or_default();
+  let operation_securities = open_api_spec.paths.paths.iter().flat_map(|(path, item)| {
+    item.operations.iter().flat_map(move |(op_type, op)| {
+      op.security
         .iter()
-        .flat_map(|p| &p.parameters)
-        .map(|p| ReferenceOr::Reference {
-          _ref: format!("#/components/parameters/{}", p.name),
-        })
-        .collect();
+        .flatten()
+        .map(move |requirement| (format!("{op_type:?} {path}"), requirement))
+    })
+  });
+  let securities = open_api_spec
+    .security
+    .iter()
+    .map(|requirement| ("Spec".to_owned(), requirement))
+    .chain(operation_securities);
+
+  for (location, requirement) in securities {
+    for (name, scopes) in &requirement.requirements {
+      match security_schemes.get(name) {
+        None => warnings.push(format!("{location}: security scheme `{name}` is not declared")),
+        Some(ReferenceOr::Object(SecurityScheme {
+          _type: SecurityType::OAuth2(oauth2),
+          ..
+        })) => {
+          let flows = &oauth2.flows;
+          let declared_scopes = flows
+            .implicit
+            .iter()
+            .map(|flow| &flow.scopes)
+            .chain(
+              [&flows.password, &flows.client_credentials, &flows.authorization_code]
+                .into_iter()
+                .flatten()
+                .map(|flow| &flow.scopes),
+            )
+            .flat_map(|scopes| scopes.keys())
+            .collect::<HashSet<&String>>();
+          for scope in scopes.iter().filter(|scope| !declared_scopes.contains(scope)) {
+            warnings.push(format!(
+              "{location}: scope `{scope}` is not declared by security scheme `{name}`"
+            ));
+          }
+        }
+        Some(_) => {}
+      }
+    }
+  }
+  warnings
+}
 
-      paths
-        .values_mut()
-        .flat_map(|pi| pi.operations.values_mut())
-        .for_each(|op| op.parameters.append(&mut parameter_refs));
+/// Exposes tag groups as `x-tagGroups`, optionally tagging operations after their scopes.
+fn assign_tag_groups(
+  open_api_spec: &mut OpenApi,
+  operations_metadata: &OperationsMetadata,
+  tag_groups: Vec<TagGroup>,
+  tags_from_scopes: bool,
+) {
+  let mut groups: IndexMap<String, Vec<String>> = tag_groups.into_iter().map(|g| (g.name, g.tags)).collect();
+
+  if tags_from_scopes {
+    for (path, item) in &mut open_api_spec.paths.paths {
+      for (op_type, op) in &mut item.operations {
+        let scope_names = operation_metadata(operations_metadata, path, op_type)
+          .map(|metadata| metadata.scope_names.as_slice())
+          .unwrap_or_default();
+        if let Some(tag) = scope_names.first() {
+          if !op.tags.contains(tag) {
+            op.tags.push(tag.clone());
+          }
+          let group = scope_names.get(1).unwrap_or(tag);
+          let group_tags = groups.entry(group.clone()).or_default();
+          if !group_tags.contains(tag) {
+            group_tags.push(tag.clone());
+          }
+        }
+      }
+    }
+  }
+
+  if groups.is_empty() {
+    return;
+  }
 
-      if let Some(c) = components.as_mut() {
-        c.parameters.append(&mut parameter_components);
-        c.schemas.append(&mut schema_components);
+  let grouped_tags = groups.values().flatten().collect::<HashSet<&String>>();
+  let ungrouped_tags = open_api_spec
+    .tags
+    .iter()
+    .map(|tag| &tag.name)
+    .chain(
+      open_api_spec
+        .paths
+        .paths
+        .values()
+        .flat_map(|item| item.operations.values())
+        .flat_map(|op| &op.tags),
+    )
+    .filter(|tag| !grouped_tags.contains(tag))
+    .collect::<IndexSet<&String>>();
+  for tag in ungrouped_tags {
+    warn!("Tag `{tag}` doesn't belong to any tag group");
+  }
+
+  let groups = groups
+    .into_iter()
+    .map(|(name, tags)| TagGroup { name, tags })
+    .collect::<Vec<TagGroup>>();
+  if let Ok(groups) = serde_json::to_value(groups) {
+    open_api_spec.extensions.insert("x-tagGroups".to_owned(), groups);
+  }
+}
+
+/// Generates missing operation ids following the given strategy, making sure every operation id is unique.
+fn assign_operation_ids(
+  paths: &mut IndexMap<String, PathItem>,
+  operations_metadata: &OperationsMetadata,
+  strategy: &OperationIdStrategy,
+) {
+  let mut used_ids: HashSet<String> = HashSet::new();
+  let mut generated_ids = vec![];
+
+  // explicit operation ids are reserved first so that generated ones never take their place
+  for (path, item) in paths.iter_mut() {
+    for (op_type, op) in &mut item.operations {
+      let handler_name =
+        operation_metadata(operations_metadata, path, op_type).and_then(|metadata| metadata.handler_name.as_deref());
+      match op.operation_id.take() {
+        Some(operation_id) => op.operation_id = Some(unique_operation_id(&operation_id, &mut used_ids)),
+        None => {
+          let context = OperationIdContext {
+            path,
+            operation_type: op_type,
+            handler_name,
+            operation: op,
+          };
+          generated_ids.push(generate_operation_id(strategy, &context));
+        }
       }
     }
+  }
 
-    if !self.default_tags.is_empty() {
-      paths
-        .values_mut()
-        .flat_map(|pi| pi.operations.values_mut())
-        .for_each(|op| op.tags.append(&mut self.default_tags.clone()))
+  let mut generated_ids = generated_ids.into_iter();
+  for op in paths.values_mut().flat_map(|item| item.operations.values_mut()) {
+    if op.operation_id.is_none() {
+      op.operation_id = generated_ids
+        .next()
+        .map(|operation_id| unique_operation_id(&operation_id, &mut used_ids));
     }
+  }
+}
 
-    open_api_spec.paths.paths = paths;
-    open_api_spec.components = components;
+/// Metadata of the operation documented for the given path and operation type.
+fn operation_metadata<'a>(
+  operations_metadata: &'a OperationsMetadata,
+  path: &str,
+  operation_type: &OperationType,
+) -> Option<&'a OperationMetadata> {
+  operations_metadata
+    .get(path)
+    .and_then(|metadata| metadata.get(operation_type))
+}
+
+fn unique_operation_id(operation_id: &str, used_ids: &mut HashSet<String>) -> String {
+  let mut unique_id = operation_id.to_owned();
+  let mut suffix = 1;
+  while used_ids.contains(&unique_id) {
+    suffix += 1;
+    unique_id = format!("{operation_id}_{suffix}");
+  }
+  if unique_id != operation_id {
+    warn!("Duplicated operation id `{operation_id}`, renamed to `{unique_id}`");
   }
+  used_ids.insert(unique_id.clone());
+  unique_id
+}
+
+fn generate_operation_id(strategy: &OperationIdStrategy, context: &OperationIdContext<'_>) -> String {
+  match strategy {
+    OperationIdStrategy::Hash => build_operation_id(context.path, context.operation_type),
+    OperationIdStrategy::HandlerName => context
+      .handler_name
+      .map(ToOwned::to_owned)
+      .unwrap_or_else(|| build_method_path_operation_id(context.path, context.operation_type)),
+    OperationIdStrategy::MethodPath => build_method_path_operation_id(context.path, context.operation_type),
+    OperationIdStrategy::TagHandler => {
+      let handler_name = generate_operation_id(&OperationIdStrategy::HandlerName, context);
+      match context.operation.tags.first() {
+        Some(tag) => format!("{tag}.{handler_name}"),
+        None => handler_name,
+      }
+    }
+    OperationIdStrategy::Custom(generate) => generate(context),
+  }
+}
+
+/// `getPetsByPetId` for `GET /pets/{pet_id}`
+fn build_method_path_operation_id(path: &str, operation_type: &OperationType) -> String {
+  let mut operation_id = format!("{operation_type:?}").to_lowercase();
+  for segment in path.split('/').filter(|s| !s.is_empty()) {
+    if let Some(parameter) = segment.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+      operation_id.push_str("By");
+      operation_id.push_str(&pascal_case(parameter));
+    } else {
+      operation_id.push_str(&pascal_case(segment));
+    }
+  }
+  operation_id
+}
+
+fn pascal_case(value: &str) -> String {
+  value
+    .split(|c: char| !c.is_alphanumeric())
+    .filter(|s| !s.is_empty())
+    .map(|s| {
+      let mut chars = s.chars();
+      chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+        .unwrap_or_default()
+    })
+    .collect()
 }
 
 #[allow(clippy::expect_used)]
@@ -275,14 +702,20 @@ fn build_operation_id(path: &str, operation_type: &OperationType) -> String {
 mod tests {
   #![allow(clippy::expect_used)]
 
-  use crate::app::{build_operation_id, OpenApiWrapper};
-  use crate::spec::Spec;
+  use crate::app::{build_method_path_operation_id, build_operation_id, security_warnings, OpenApiWrapper};
+  use crate::spec::{DefaultSecurity, Spec};
   use actix_web::test::{call_service, init_service, try_read_body_json, TestRequest};
   use actix_web::App;
+  use apistos_models::components::Components;
   use apistos_models::info::Info;
   use apistos_models::paths::OperationType;
+  use apistos_models::reference_or::ReferenceOr;
+  use apistos_models::security::{
+    OAuth2, OauthFlows, OauthImplicit, SecurityRequirement, SecurityScheme, SecurityType,
+  };
   use apistos_models::tag::Tag;
   use apistos_models::OpenApi;
+  use std::collections::BTreeMap;
 
   #[actix_web::test]
   async fn open_api_available() {
@@ -429,4 +862,77 @@ mod tests {
     let operation_id = build_operation_id("/api/v1/plip/{test_id}/test/", &OperationType::Get);
     assert_eq!(operation_id, "get_api-v1-plip-f5c9e39d7a1acb928c72745f3893bce8")
   }
+
+  #[test]
+  fn test_security_warnings() {
+    let open_api_spec = OpenApi {
+      components: Some(Components {
+        security_schemes: BTreeMap::from_iter([(
+          "oauth".to_owned(),
+          ReferenceOr::Object(SecurityScheme {
+            _type: SecurityType::OAuth2(OAuth2 {
+              flows: OauthFlows {
+                implicit: Some(OauthImplicit {
+                  authorization_url: "https://authorize.com".to_owned(),
+                  refresh_url: None,
+                  scopes: BTreeMap::from_iter([("read:pets".to_owned(), "Read pets".to_owned())]),
+                }),
+                ..Default::default()
+              },
+            }),
+            description: None,
+            extensions: Default::default(),
+          }),
+        )]),
+        ..Default::default()
+      }),
+      security: vec![SecurityRequirement {
+        requirements: BTreeMap::from_iter([
+          (
+            "oauth".to_owned(),
+            vec!["read:pets".to_owned(), "write:pets".to_owned()],
+          ),
+          ("api_key".to_owned(), vec![]),
+        ]),
+      }],
+      ..Default::default()
+    };
+
+    assert_eq!(
+      security_warnings(&open_api_spec),
+      vec![
+        "Spec: security scheme `api_key` is not declared",
+        "Spec: scope `write:pets` is not declared by security scheme `oauth`"
+      ]
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "Spec: security scheme `api_key` is not declared")]
+  fn strict_security_rejects_undeclared_securities() {
+    let spec = Spec {
+      security: vec![DefaultSecurity {
+        requirement: SecurityRequirement {
+          requirements: BTreeMap::from_iter([("api_key".to_owned(), vec![])]),
+        },
+        security_schemes: BTreeMap::new(),
+      }],
+      strict_security: true,
+      ..Default::default()
+    };
+
+    let _app = App::new().document(spec).build("/openapi.json");
+  }
+
+  #[test]
+  fn test_build_method_path_operation_id() {
+    let operation_id = build_method_path_operation_id("/api/v1/plop/", &OperationType::Get);
+    assert_eq!(operation_id, "getApiV1Plop");
+
+    let operation_id = build_method_path_operation_id("/api/v1/plap/{test_id}", &OperationType::Delete);
+    assert_eq!(operation_id, "deleteApiV1PlapByTestId");
+
+    let operation_id = build_method_path_operation_id("/api/v1/plip-plop/{test_id}/test/", &OperationType::Post);
+    assert_eq!(operation_id, "postApiV1PlipPlopByTestIdTest")
+  }
 }
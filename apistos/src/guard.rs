@@ -0,0 +1,458 @@
+//! Documented drop-in replacements for [`actix_web::guard`](https://docs.rs/actix-web/*/actix_web/guard/index.html).
+//!
+//! Actix guards are opaque, apistos can't tell what they filter on. Guards implementing [`DocumentedGuard`] can be
+//! registered using `guard_documented` on [`Route`](crate::web::Route), [`Resource`](crate::web::Resource) and
+//! [`Scope`](crate::web::Scope) to narrow documented methods, declare consumed media types or attach servers.
+//!
+//! ```rust
+//! use apistos::guard;
+//! use apistos::web::{resource, Resource};
+//!
+//! let resource: Resource = resource("/pets")
+//!   .guard_documented(guard::Header("content-type", "application/json"))
+//!   .guard_documented(guard::Any(guard::Get()).or(guard::Post()));
+//! ```
+#![allow(non_snake_case)]
+
+use crate::internal::actix::utils::operation_type_from_method;
+use actix_web::guard::{Guard, GuardContext};
+use actix_web::http::header::CONTENT_TYPE;
+use actix_web::http::Method as HttpMethod;
+use apistos_models::paths::{Operation, OperationType, PathItem, RequestBody};
+use apistos_models::reference_or::ReferenceOr;
+use apistos_models::server::Server;
+use indexmap::IndexMap;
+use log::warn;
+
+/// A [`Guard`] which can describe its effect on the generated documentation.
+///
+/// Every method has a default implementation leaving the documentation untouched, so custom guards only have to
+/// implement what they actually filter on.
+pub trait DocumentedGuard: Guard {
+  /// Operation types this guard lets through. `None` means the guard doesn't filter on method.
+  fn methods(&self) -> Option<Vec<OperationType>> {
+    None
+  }
+
+  /// Media types this guard restricts request bodies to. `None` means the guard doesn't filter on media type, an empty
+  /// list that no media type goes through.
+  fn consumes(&self) -> Option<Vec<String>> {
+    None
+  }
+
+  /// Servers this guard restricts requests to.
+  fn servers(&self) -> Vec<Server> {
+    vec![]
+  }
+}
+
+/// Guard matching a given http method. Drop in for [`actix_web::guard::Method`](https://docs.rs/actix-web/*/actix_web/guard/fn.Method.html).
+pub struct MethodGuard {
+  method: HttpMethod,
+  inner: Box<dyn Guard>,
+}
+
+impl Guard for MethodGuard {
+  fn check(&self, ctx: &GuardContext<'_>) -> bool {
+    self.inner.check(ctx)
+  }
+}
+
+impl DocumentedGuard for MethodGuard {
+  fn methods(&self) -> Option<Vec<OperationType>> {
+    Some(operation_type_from_method(&self.method).into_iter().collect())
+  }
+}
+
+/// Creates a guard that matches a specified HTTP method.
+pub fn Method(method: HttpMethod) -> MethodGuard {
+  MethodGuard {
+    inner: Box::new(actix_web::guard::Method(method.clone())),
+    method,
+  }
+}
+
+macro_rules! method_guard {
+  ($method_fn:ident, $method_const:ident) => {
+    #[doc = concat!("Creates a guard that matches the `", stringify!($method_const), "` request method.")]
+    pub fn $method_fn() -> MethodGuard {
+      Method(HttpMethod::$method_const)
+    }
+  };
+}
+
+method_guard!(Get, GET);
+method_guard!(Post, POST);
+method_guard!(Put, PUT);
+method_guard!(Delete, DELETE);
+method_guard!(Head, HEAD);
+method_guard!(Options, OPTIONS);
+method_guard!(Patch, PATCH);
+method_guard!(Trace, TRACE);
+
+/// Guard matching a header name and value. Drop in for [`actix_web::guard::Header`](https://docs.rs/actix-web/*/actix_web/guard/fn.Header.html).
+///
+/// When used on `content-type`, the value is documented as the only media type consumed by the guarded operations.
+pub struct HeaderGuard {
+  name: &'static str,
+  value: &'static str,
+  inner: Box<dyn Guard>,
+}
+
+impl Guard for HeaderGuard {
+  fn check(&self, ctx: &GuardContext<'_>) -> bool {
+    self.inner.check(ctx)
+  }
+}
+
+impl DocumentedGuard for HeaderGuard {
+  fn consumes(&self) -> Option<Vec<String>> {
+    CONTENT_TYPE
+      .as_str()
+      .eq_ignore_ascii_case(self.name)
+      .then(|| vec![self.value.to_string()])
+  }
+}
+
+/// Creates a guard that matches if request contains given header name and value.
+pub fn Header(name: &'static str, value: &'static str) -> HeaderGuard {
+  HeaderGuard {
+    name,
+    value,
+    inner: Box::new(actix_web::guard::Header(name, value)),
+  }
+}
+
+/// Guard matching the request host. Drop in for [`actix_web::guard::Host`](https://docs.rs/actix-web/*/actix_web/guard/fn.Host.html).
+///
+/// The host (and scheme if any) is documented as a server for the guarded operations.
+pub struct HostGuard {
+  host: String,
+  scheme: Option<String>,
+  inner: actix_web::guard::HostGuard,
+}
+
+impl HostGuard {
+  /// Set request scheme to match
+  pub fn scheme<H: AsRef<str>>(mut self, scheme: H) -> HostGuard {
+    self.scheme = Some(scheme.as_ref().to_string());
+    self.inner = self.inner.scheme(scheme);
+    self
+  }
+}
+
+impl Guard for HostGuard {
+  fn check(&self, ctx: &GuardContext<'_>) -> bool {
+    self.inner.check(ctx)
+  }
+}
+
+impl DocumentedGuard for HostGuard {
+  fn servers(&self) -> Vec<Server> {
+    let url = match &self.scheme {
+      Some(scheme) => format!("{scheme}://{}", self.host),
+      None => format!("//{}", self.host),
+    };
+    vec![Server {
+      url,
+      ..Default::default()
+    }]
+  }
+}
+
+/// Creates a guard that matches requests targeting a specific host.
+pub fn Host(host: impl AsRef<str>) -> HostGuard {
+  HostGuard {
+    host: host.as_ref().to_string(),
+    scheme: None,
+    inner: actix_web::guard::Host(host),
+  }
+}
+
+/// Guard matching if any of the given guards matches. Drop in for [`actix_web::guard::Any`](https://docs.rs/actix-web/*/actix_web/guard/fn.Any.html).
+///
+/// Documented methods, media types and servers are the union of the ones of each guard.
+pub struct AnyGuard {
+  guards: Vec<Box<dyn DocumentedGuard>>,
+}
+
+impl AnyGuard {
+  /// Adds new guard to the collection of guards to check.
+  pub fn or<G: DocumentedGuard + 'static>(mut self, guard: G) -> Self {
+    self.guards.push(Box::new(guard));
+    self
+  }
+}
+
+impl Guard for AnyGuard {
+  fn check(&self, ctx: &GuardContext<'_>) -> bool {
+    self.guards.iter().any(|guard| guard.check(ctx))
+  }
+}
+
+impl DocumentedGuard for AnyGuard {
+  fn methods(&self) -> Option<Vec<OperationType>> {
+    // if a single guard doesn't filter on methods, any method can go through
+    let mut methods: Vec<OperationType> = vec![];
+    for guard_methods in self.guards.iter().map(|guard| guard.methods()) {
+      for method in guard_methods? {
+        if !methods.contains(&method) {
+          methods.push(method);
+        }
+      }
+    }
+    Some(methods)
+  }
+
+  fn consumes(&self) -> Option<Vec<String>> {
+    // if a single guard doesn't filter on media types, any media type can go through
+    let mut consumes: Vec<String> = vec![];
+    for guard_consumes in self.guards.iter().map(|guard| guard.consumes()) {
+      for content_type in guard_consumes? {
+        if !consumes.contains(&content_type) {
+          consumes.push(content_type);
+        }
+      }
+    }
+    Some(consumes)
+  }
+
+  fn servers(&self) -> Vec<Server> {
+    let mut servers: Vec<Server> = vec![];
+    for guard_servers in self.guards.iter().map(|guard| guard.servers()) {
+      if guard_servers.is_empty() {
+        return vec![];
+      }
+      servers.extend(guard_servers);
+    }
+    servers
+  }
+}
+
+/// Creates a guard that matches if any added guards match.
+pub fn Any<G: DocumentedGuard + 'static>(guard: G) -> AnyGuard {
+  AnyGuard {
+    guards: vec![Box::new(guard)],
+  }
+}
+
+/// Guard matching if all of the given guards match. Drop in for [`actix_web::guard::All`](https://docs.rs/actix-web/*/actix_web/guard/fn.All.html).
+pub struct AllGuard {
+  documentation: GuardDocumentation,
+  guards: Vec<Box<dyn Guard>>,
+}
+
+impl AllGuard {
+  /// Adds new guard to the collection of guards to check.
+  pub fn and<G: DocumentedGuard + 'static>(mut self, guard: G) -> Self {
+    self.documentation.add(&guard);
+    self.guards.push(Box::new(guard));
+    self
+  }
+}
+
+impl Guard for AllGuard {
+  fn check(&self, ctx: &GuardContext<'_>) -> bool {
+    self.guards.iter().all(|guard| guard.check(ctx))
+  }
+}
+
+impl DocumentedGuard for AllGuard {
+  fn methods(&self) -> Option<Vec<OperationType>> {
+    self.documentation.methods.clone()
+  }
+
+  fn consumes(&self) -> Option<Vec<String>> {
+    self.documentation.consumes.clone()
+  }
+
+  fn servers(&self) -> Vec<Server> {
+    self.documentation.servers.clone()
+  }
+}
+
+/// Creates a guard that matches if all added guards match.
+pub fn All<G: DocumentedGuard + 'static>(guard: G) -> AllGuard {
+  AllGuard {
+    documentation: GuardDocumentation::default(),
+    guards: vec![],
+  }
+  .and(guard)
+}
+
+/// Accumulated documentation for every guard registered on a route, resource or scope.
+#[derive(Default, Clone)]
+pub(crate) struct GuardDocumentation {
+  methods: Option<Vec<OperationType>>,
+  consumes: Option<Vec<String>>,
+  servers: Vec<Server>,
+}
+
+impl GuardDocumentation {
+  /// Guards are cumulative, only what is accepted by every guard is kept.
+  pub(crate) fn add<G: DocumentedGuard + ?Sized>(&mut self, guard: &G) {
+    if let Some(methods) = guard.methods() {
+      self.methods = Some(match self.methods.take() {
+        None => methods,
+        Some(current) => current.into_iter().filter(|m| methods.contains(m)).collect(),
+      });
+    }
+
+    if let Some(consumes) = guard.consumes() {
+      let consumes = match self.consumes.take() {
+        None => consumes,
+        Some(current) => current.into_iter().filter(|c| consumes.contains(c)).collect(),
+      };
+      if consumes.is_empty() {
+        warn!("Guards don't accept any common media type, request bodies are documented without content");
+      }
+      self.consumes = Some(consumes);
+    }
+
+    self.servers.extend(guard.servers());
+  }
+
+  /// Restricts operations to documented methods and media types. Servers are documented on each operation.
+  pub(crate) fn update_operations(&self, operations: &mut IndexMap<OperationType, Operation>) {
+    self.filter_operations(operations);
+    if !self.servers.is_empty() {
+      for operation in operations.values_mut() {
+        operation.servers.extend(self.servers.clone());
+      }
+    }
+  }
+
+  /// Restricts path item operations to documented methods and media types. Servers are documented on the path item.
+  pub(crate) fn update_path_item(&self, path_item: &mut PathItem) {
+    self.filter_operations(&mut path_item.operations);
+    path_item.server.extend(self.servers.clone());
+  }
+
+  fn filter_operations(&self, operations: &mut IndexMap<OperationType, Operation>) {
+    if let Some(methods) = &self.methods {
+      operations.retain(|operation_type, _| methods.contains(operation_type));
+    }
+    if let Some(consumes) = &self.consumes {
+      for operation in operations.values_mut() {
+        if let Some(ReferenceOr::Object(request_body)) = operation.request_body.as_mut() {
+          restrict_request_body_content(request_body, consumes);
+        }
+      }
+    }
+  }
+}
+
+fn restrict_request_body_content(request_body: &mut RequestBody, consumes: &[String]) {
+  if let Some(media_type) = request_body.content.values().next().cloned() {
+    request_body.content = consumes
+      .iter()
+      .map(|content_type| {
+        let media_type = request_body
+          .content
+          .get(content_type)
+          .cloned()
+          .unwrap_or_else(|| media_type.clone());
+        (content_type.clone(), media_type)
+      })
+      .collect();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::guard::{All, Any, Get, GuardDocumentation, Header, Host, Post, Put};
+  use apistos_models::paths::{MediaType, Operation, OperationType, PathItem, RequestBody};
+  use apistos_models::reference_or::ReferenceOr;
+  use indexmap::IndexMap;
+  use std::collections::BTreeMap;
+
+  fn path_item() -> PathItem {
+    let operation = Operation {
+      request_body: Some(ReferenceOr::Object(RequestBody {
+        content: BTreeMap::from_iter(vec![("application/json".to_string(), MediaType::default())]),
+        ..Default::default()
+      })),
+      ..Default::default()
+    };
+    PathItem {
+      operations: IndexMap::from_iter(vec![
+        (OperationType::Get, operation.clone()),
+        (OperationType::Post, operation.clone()),
+        (OperationType::Put, operation),
+      ]),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn method_guards_narrow_operations() {
+    let mut documentation = GuardDocumentation::default();
+    documentation.add(&Any(Get()).or(Post()));
+    documentation.add(&All(Any(Post()).or(Put())));
+
+    let mut path_item = path_item();
+    documentation.update_path_item(&mut path_item);
+
+    assert_eq!(
+      path_item.operations.keys().cloned().collect::<Vec<_>>(),
+      vec![OperationType::Post]
+    );
+  }
+
+  #[test]
+  fn content_type_guard_replaces_consumed_media_types() {
+    let mut documentation = GuardDocumentation::default();
+    documentation.add(&Any(Header("Content-Type", "application/xml")).or(Header("content-type", "text/plain")));
+    documentation.add(&Header("x-custom", "value"));
+
+    let mut path_item = path_item();
+    documentation.update_path_item(&mut path_item);
+
+    let content_types = path_item
+      .operations
+      .values()
+      .filter_map(|op| op.request_body.clone().and_then(|b| b.get_object()))
+      .map(|b| b.content.keys().cloned().collect::<Vec<_>>())
+      .collect::<Vec<_>>();
+    assert_eq!(content_types.len(), 3);
+    for content_type in content_types {
+      assert_eq!(
+        content_type,
+        vec!["application/xml".to_string(), "text/plain".to_string()]
+      );
+    }
+  }
+
+  #[test]
+  fn disjoint_content_type_guards_consume_nothing() {
+    let mut documentation = GuardDocumentation::default();
+    documentation.add(&All(Header("content-type", "application/xml")).and(Header("content-type", "text/plain")));
+
+    let mut path_item = path_item();
+    documentation.update_path_item(&mut path_item);
+
+    assert!(path_item
+      .operations
+      .values()
+      .filter_map(|op| op.request_body.clone().and_then(|b| b.get_object()))
+      .all(|b| b.content.is_empty()));
+  }
+
+  #[test]
+  fn host_guard_adds_servers() {
+    let mut documentation = GuardDocumentation::default();
+    documentation.add(&Host("api.netwo.io").scheme("https"));
+    documentation.add(&Host("netwo.io"));
+
+    let mut path_item = path_item();
+    documentation.update_path_item(&mut path_item);
+    assert_eq!(
+      path_item.server.iter().map(|s| s.url.clone()).collect::<Vec<_>>(),
+      vec!["https://api.netwo.io".to_string(), "//netwo.io".to_string()]
+    );
+
+    let mut operations = path_item.operations;
+    documentation.update_operations(&mut operations);
+    assert!(operations.values().all(|op| op.servers.len() == 2));
+  }
+}
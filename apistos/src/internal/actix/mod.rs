@@ -6,7 +6,7 @@ pub(crate) mod route;
 pub(crate) mod scope;
 pub(crate) mod service_config;
 
-mod utils;
+pub(crate) mod utils;
 
 const METHODS: &[OperationType] = &[
   OperationType::Get,
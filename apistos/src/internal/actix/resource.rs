@@ -1,21 +1,31 @@
+use crate::guard::{DocumentedGuard, GuardDocumentation};
 use crate::internal::actix::route::{Route, RouteWrapper};
-use crate::internal::actix::utils::OperationUpdater;
+use crate::internal::actix::utils::{OperationMetadata, OperationUpdater};
 use crate::internal::actix::METHODS;
-use actix_service::{ServiceFactory, Transform};
+use crate::middleware::{DocumentedMiddleware, DocumentedTransform};
+use crate::spec::DefaultParameters;
+use actix_service::{Service, ServiceFactory, Transform};
 use actix_web::body::MessageBody;
 use actix_web::dev::{AppService, HttpServiceFactory, ServiceRequest, ServiceResponse};
 use actix_web::guard::Guard;
 use actix_web::{Error, FromRequest, Handler, Responder};
 use apistos_core::PathItemDefinition;
 use apistos_models::components::Components;
-use apistos_models::paths::PathItem;
+use apistos_models::paths::{OperationType, PathItem};
+use apistos_models::server::Server;
+use indexmap::IndexMap;
 use std::fmt::Debug;
 use std::future::Future;
+use std::rc::Rc;
 
 pub struct Resource<R = actix_web::Resource> {
   pub(crate) path: String,
   pub(crate) item_definition: Option<PathItem>,
+  pub(crate) operations_metadata: IndexMap<OperationType, OperationMetadata>,
   pub(crate) components: Vec<Components>,
+  pub(crate) guard_documentation: GuardDocumentation,
+  pub(crate) transforms: Vec<Rc<dyn DocumentedTransform>>,
+  pub(crate) default_parameters: Vec<DefaultParameters>,
   tags: Vec<String>,
   inner: R,
 }
@@ -26,7 +36,11 @@ impl Resource {
     Resource {
       path: path.to_owned(),
       item_definition: None,
+      operations_metadata: Default::default(),
       components: Default::default(),
+      guard_documentation: Default::default(),
+      transforms: Default::default(),
+      default_parameters: Default::default(),
       tags: Default::default(),
       inner: actix_web::Resource::new(path),
     }
@@ -38,7 +52,11 @@ impl Resource {
     Resource {
       path: path.to_owned(),
       item_definition: None,
+      operations_metadata: Default::default(),
       components: Default::default(),
+      guard_documentation: Default::default(),
+      transforms: Default::default(),
+      default_parameters: Default::default(),
       tags: tags.into_iter().map(Into::into).collect(),
       inner: actix_web::Resource::new(path),
     }
@@ -70,12 +88,46 @@ where
 
   /// Proxy for [`actix_web::Resource::guard`](https://docs.rs/actix-web/*/actix_web/struct.Resource.html#method.guard).
   ///
-  /// **NOTE:** This doesn't affect spec generation.
+  /// **NOTE:** This doesn't affect spec generation. Use [`Resource::guard_documented`] for guards implementing [`DocumentedGuard`].
   pub fn guard<G: Guard + 'static>(mut self, guard: G) -> Self {
     self.inner = self.inner.guard(guard);
     self
   }
 
+  /// Wrapper for [`actix_web::Resource::guard`](https://docs.rs/actix-web/*/actix_web/struct.Resource.html#method.guard) documenting the guard effect on every route of this resource.
+  pub fn guard_documented<G: DocumentedGuard + 'static>(mut self, guard: G) -> Self {
+    self.guard_documentation.add(&guard);
+    self.inner = self.inner.guard(guard);
+    self
+  }
+
+  /// Set the summary applying to every route of this resource.
+  pub fn summary(mut self, summary: &str) -> Self {
+    self.item_definition.get_or_insert_with(Default::default).summary = Some(summary.to_owned());
+    self
+  }
+
+  /// Set the description applying to every route of this resource. [CommonMark syntax](https://spec.commonmark.org/) may be used.
+  pub fn description(mut self, description: &str) -> Self {
+    self.item_definition.get_or_insert_with(Default::default).description = Some(description.to_owned());
+    self
+  }
+
+  /// Set alternative servers serving every route of this resource.
+  pub fn servers(mut self, servers: Vec<Server>) -> Self {
+    self.item_definition.get_or_insert_with(Default::default).server = servers;
+    self
+  }
+
+  /// Add default parameters to every route of this resource matching their filter.
+  ///
+  /// Parameters are exposed as components, see [`DefaultParameters`].
+  pub fn default_parameters(mut self, default_parameters: DefaultParameters) -> Self {
+    self.components.push(default_parameters.to_components());
+    self.default_parameters.push(default_parameters);
+    self
+  }
+
   /// Wrapper for [`actix_web::Resource::route`](https://docs.rs/actix-web/*/actix_web/struct.Resource.html#method.route).
   pub fn route(mut self, route: Route) -> Self {
     let w = RouteWrapper::new(&self.path, route);
@@ -86,6 +138,7 @@ where
     }
     item_definition.operations.extend(operations);
     self.item_definition = Some(item_definition);
+    self.operations_metadata.extend(w.def.metadata);
     self.components.extend(w.component);
     self.inner = self.inner.route(w.inner);
     self
@@ -110,12 +163,14 @@ where
   {
     if F::Future::is_visible() {
       let mut operation = F::Future::operation();
+      let metadata = OperationMetadata::new::<F::Future>();
       operation.tags.append(&mut self.tags.clone());
+      operation.update_path_parameter_name_from_path(&self.path);
       let mut item_definition = self.item_definition.unwrap_or_default();
       for method in METHODS {
         item_definition.operations.insert(method.clone(), operation.clone());
+        self.operations_metadata.insert(method.clone(), metadata.clone());
       }
-      operation.update_path_parameter_name_from_path(&self.path);
       self.item_definition = Some(item_definition);
       self.components.extend(F::Future::components());
     }
@@ -125,9 +180,10 @@ where
 
   /// Proxy for [`actix_web::web::Resource::wrap`](https://docs.rs/actix-web/*/actix_web/struct.Resource.html#method.wrap).
   ///
-  /// **NOTE:** This doesn't affect spec generation.
+  /// **NOTE:** Only middlewares wrapped in a [`DocumentedMiddleware`] affect spec generation, their effect being
+  /// documented on every route of this resource.
   pub fn wrap<M, B>(
-    self,
+    mut self,
     mw: M,
   ) -> Resource<
     actix_web::Resource<
@@ -135,18 +191,48 @@ where
     >,
   >
   where
-    B: MessageBody,
+    B: MessageBody + 'static,
     M: Transform<T::Service, ServiceRequest, Response = ServiceResponse<B>, Error = Error, InitError = ()> + 'static,
+    T::Service: 'static,
   {
+    self
+      .transforms
+      .extend(DocumentedMiddleware::<T::Service, B>::transform_of(&mw));
     Resource {
       path: self.path,
       item_definition: self.item_definition,
+      operations_metadata: self.operations_metadata,
       components: self.components,
+      guard_documentation: self.guard_documentation,
+      transforms: self.transforms,
+      default_parameters: self.default_parameters,
       tags: self.tags,
       inner: self.inner.wrap(mw),
     }
   }
 
+  /// Wrapper for [`actix_web::web::Resource::wrap`](https://docs.rs/actix-web/*/actix_web/struct.Resource.html#method.wrap) documenting the middleware effect on every route of this resource, shorthand for `wrap(DocumentedMiddleware::new(mw))`.
+  pub fn wrap_documented<M, B>(
+    self,
+    mw: M,
+  ) -> Resource<
+    actix_web::Resource<
+      impl ServiceFactory<ServiceRequest, Config = (), Response = ServiceResponse<B>, Error = Error, InitError = ()>,
+    >,
+  >
+  where
+    B: MessageBody + 'static,
+    M: Transform<T::Service, ServiceRequest, Response = ServiceResponse<B>, Error = Error, InitError = ()>
+      + DocumentedTransform
+      + 'static,
+    M::Transform: 'static,
+    M::Future: 'static,
+    <M::Transform as Service<ServiceRequest>>::Future: 'static,
+    T::Service: 'static,
+  {
+    self.wrap(DocumentedMiddleware::new(mw))
+  }
+
   /// Proxy for [`actix_web::web::Resource::wrap_fn`](https://docs.rs/actix-web/*/actix_web/struct.Resource.html#method.wrap_fn).
   ///
   /// **NOTE:** This doesn't affect spec generation.
@@ -166,7 +252,11 @@ where
     Resource {
       path: self.path,
       item_definition: self.item_definition,
+      operations_metadata: self.operations_metadata,
       components: self.components,
+      guard_documentation: self.guard_documentation,
+      transforms: self.transforms,
+      default_parameters: self.default_parameters,
       tags: self.tags,
       inner: self.inner.wrap_fn(mw),
     }
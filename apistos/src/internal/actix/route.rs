@@ -1,4 +1,5 @@
-use crate::internal::actix::utils::OperationUpdater;
+use crate::guard::{DocumentedGuard, GuardDocumentation};
+use crate::internal::actix::utils::{operation_type_from_method, OperationMetadata, OperationUpdater};
 use crate::internal::actix::METHODS;
 use actix_service::ServiceFactory;
 use actix_web::dev::ServiceRequest;
@@ -59,8 +60,10 @@ pub enum OperationTypeDoc {
 
 pub struct Route {
   operation: Option<Operation>,
+  metadata: OperationMetadata,
   path_item_type: OperationTypeDoc,
   components: Vec<Components>,
+  guard_documentation: GuardDocumentation,
   inner: actix_web::Route,
 }
 
@@ -85,25 +88,20 @@ impl Route {
   pub fn new() -> Route {
     Route {
       operation: None,
+      metadata: Default::default(),
       path_item_type: OperationTypeDoc::AllMethods,
       components: Default::default(),
+      guard_documentation: Default::default(),
       inner: actix_web::Route::new(),
     }
   }
 
   /// Wrapper for [`actix_web::Route::method`](https://docs.rs/actix-web/*/actix_web/struct.Route.html#method.method)
   pub fn method(mut self, method: Method) -> Self {
-    let path_item_type = match method.as_str() {
-      "PUT" => OperationTypeDoc::OperationType(OperationType::Put),
-      "POST" => OperationTypeDoc::OperationType(OperationType::Post),
-      "DELETE" => OperationTypeDoc::OperationType(OperationType::Delete),
-      "OPTIONS" => OperationTypeDoc::OperationType(OperationType::Options),
-      "HEAD" => OperationTypeDoc::OperationType(OperationType::Head),
-      "PATCH" => OperationTypeDoc::OperationType(OperationType::Patch),
-      "TRACE" => OperationTypeDoc::OperationType(OperationType::Trace),
-      "GET" => OperationTypeDoc::OperationType(OperationType::Get),
-      m => {
-        warn!("Unsupported method found: {m}, operation will not be documented");
+    let path_item_type = match operation_type_from_method(&method) {
+      Some(operation_type) => OperationTypeDoc::OperationType(operation_type),
+      None => {
+        warn!("Unsupported method found: {method}, operation will not be documented");
         OperationTypeDoc::Undocumented
       }
     };
@@ -114,12 +112,19 @@ impl Route {
 
   /// Proxy for [`actix_web::Route::guard`](https://docs.rs/actix-web/*/actix_web/struct.Route.html#method.guard).
   ///
-  /// **NOTE:** This doesn't affect spec generation.
+  /// **NOTE:** This doesn't affect spec generation. Use [`Route::guard_documented`] for guards implementing [`DocumentedGuard`].
   pub fn guard<G: Guard + 'static>(mut self, guard: G) -> Self {
     self.inner = self.inner.guard(guard);
     self
   }
 
+  /// Wrapper for [`actix_web::Route::guard`](https://docs.rs/actix-web/*/actix_web/struct.Route.html#method.guard) documenting the guard effect on this route.
+  pub fn guard_documented<G: DocumentedGuard + 'static>(mut self, guard: G) -> Self {
+    self.guard_documentation.add(&guard);
+    self.inner = self.inner.guard(guard);
+    self
+  }
+
   /// Wrapper for [`actix_web::Route::to`](https://docs.rs/actix-web/*/actix_web/struct.Route.html#method.to)
   pub fn to<F, Args>(mut self, handler: F) -> Self
   where
@@ -130,6 +135,7 @@ impl Route {
   {
     if F::Future::is_visible() {
       self.operation = Some(F::Future::operation());
+      self.metadata = OperationMetadata::new::<F::Future>();
       self.components = F::Future::components();
     }
     self.inner = self.inner.to(handler);
@@ -140,6 +146,7 @@ impl Route {
 pub(crate) struct PathDefinition {
   pub(crate) path: String,
   pub(crate) item: PathItem,
+  pub(crate) metadata: IndexMap<OperationType, OperationMetadata>,
 }
 
 pub(crate) struct RouteWrapper {
@@ -151,6 +158,7 @@ pub(crate) struct RouteWrapper {
 impl RouteWrapper {
   pub(crate) fn new<S: Into<String>>(path: S, route: Route) -> Self {
     let mut operations: IndexMap<OperationType, Operation> = Default::default();
+    let mut metadata: IndexMap<OperationType, OperationMetadata> = Default::default();
     let mut path_item = PathItem::default();
     let path: String = path.into();
     if let Some(mut operation) = route.operation {
@@ -158,20 +166,27 @@ impl RouteWrapper {
 
       match route.path_item_type {
         OperationTypeDoc::OperationType(path_item_type) => {
+          metadata.insert(path_item_type.clone(), route.metadata);
           operations.insert(path_item_type, operation);
         }
         OperationTypeDoc::AllMethods => {
           for path_item_type in METHODS {
+            metadata.insert(path_item_type.clone(), route.metadata.clone());
             operations.insert(path_item_type.clone(), operation.clone());
           }
         }
         OperationTypeDoc::Undocumented => {}
       }
+      route.guard_documentation.update_operations(&mut operations);
     }
     path_item.operations = operations;
 
     Self {
-      def: PathDefinition { path, item: path_item },
+      def: PathDefinition {
+        path,
+        item: path_item,
+        metadata,
+      },
       component: route.components,
       inner: route.inner,
     }
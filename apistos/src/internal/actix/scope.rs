@@ -1,22 +1,37 @@
+use crate::guard::{DocumentedGuard, GuardDocumentation};
 use crate::internal::actix::route::{Route, RouteWrapper};
 use crate::internal::actix::service_config::ServiceConfig;
-use crate::internal::actix::utils::OperationUpdater;
-use crate::internal::definition_holder::DefinitionHolder;
-use actix_service::{ServiceFactory, Transform};
+use crate::internal::actix::utils::{scope_name, OperationUpdater};
+use crate::internal::definition_holder::{DefinitionHolder, DocumentedPathItem};
+use crate::middleware::{DocumentedMiddleware, DocumentedTransform};
+use crate::spec::DefaultParameters;
+use actix_service::{Service, ServiceFactory, Transform};
 use actix_web::body::MessageBody;
 use actix_web::dev::{AppService, HttpServiceFactory, ServiceRequest, ServiceResponse};
 use actix_web::guard::Guard;
 use actix_web::Error;
+use apistos_core::{ApiComponent, ApiErrorComponent};
 use apistos_models::components::Components;
-use apistos_models::paths::PathItem;
+use apistos_models::paths::{Operation, Parameter, ParameterIn, Response};
+use apistos_models::reference_or::ReferenceOr;
+use apistos_models::security::SecurityRequirement;
+use apistos_models::server::Server;
 use indexmap::IndexMap;
 use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::future::Future;
+use std::rc::Rc;
 
 pub struct Scope<S = actix_web::Scope> {
-  pub(crate) item_map: BTreeMap<String, PathItem>,
+  pub(crate) item_map: BTreeMap<String, DocumentedPathItem>,
   pub(crate) components: Vec<Components>,
+  pub(crate) guard_documentation: GuardDocumentation,
+  pub(crate) transforms: Vec<Rc<dyn DocumentedTransform>>,
+  pub(crate) default_parameters: Vec<DefaultParameters>,
+  pub(crate) servers: Vec<Server>,
+  pub(crate) securities: Vec<SecurityRequirement>,
+  pub(crate) default_responses: Vec<(String, Response)>,
+  pub(crate) path_parameters: Vec<Parameter>,
   tags: Vec<String>,
   path: String,
   inner: Option<S>,
@@ -28,6 +43,13 @@ impl Scope {
     Scope {
       item_map: Default::default(),
       components: Default::default(),
+      guard_documentation: Default::default(),
+      transforms: Default::default(),
+      default_parameters: Default::default(),
+      servers: Default::default(),
+      securities: Default::default(),
+      default_responses: Default::default(),
+      path_parameters: Default::default(),
       tags: Default::default(),
       path: path.into(),
       inner: Some(actix_web::Scope::new(path)),
@@ -40,6 +62,13 @@ impl Scope {
     Scope {
       item_map: Default::default(),
       components: Default::default(),
+      guard_documentation: Default::default(),
+      transforms: Default::default(),
+      default_parameters: Default::default(),
+      servers: Default::default(),
+      securities: Default::default(),
+      default_responses: Default::default(),
+      path_parameters: Default::default(),
       tags: tags.into_iter().map(Into::into).collect(),
       path: path.into(),
       inner: Some(actix_web::Scope::new(path)),
@@ -66,12 +95,109 @@ where
 {
   /// Proxy for [`actix_web::Scope::guard`](https://docs.rs/actix-web/*/actix_web/struct.Scope.html#method.guard).
   ///
-  /// **NOTE:** This doesn't affect spec generation.
+  /// **NOTE:** This doesn't affect spec generation. Use [`Scope::guard_documented`] for guards implementing [`DocumentedGuard`].
   pub fn guard<G: Guard + 'static>(mut self, guard: G) -> Self {
     self.inner = self.inner.take().map(|s| s.guard(guard));
     self
   }
 
+  /// Wrapper for [`actix_web::Scope::guard`](https://docs.rs/actix-web/*/actix_web/struct.Scope.html#method.guard) documenting the guard effect on every operation of this scope.
+  pub fn guard_documented<G: DocumentedGuard + 'static>(mut self, guard: G) -> Self {
+    self.guard_documentation.add(&guard);
+    self.inner = self.inner.take().map(|s| s.guard(guard));
+    self
+  }
+
+  /// Add default parameters to every operation of this scope, nested scopes included matching their filter.
+  ///
+  /// Parameters are exposed as components, see [`DefaultParameters`].
+  pub fn default_parameters(mut self, default_parameters: DefaultParameters) -> Self {
+    self.components.push(default_parameters.to_components());
+    self.default_parameters.push(default_parameters);
+    self
+  }
+
+  /// Set alternative servers serving every operation of this scope, nested scopes included.
+  pub fn servers(mut self, servers: Vec<Server>) -> Self {
+    self.servers = servers;
+    self
+  }
+
+  /// Document every operation of this scope, nested scopes included, as requiring the given security, usually a type
+  /// deriving `ApiSecurity`. Calling it multiple times declares alternative securities.
+  ///
+  /// Operations declaring their own security, or documented with `#[api_operation(security = none)]`, are left untouched.
+  pub fn security<S: ApiComponent>(self) -> Self {
+    self.security_with_scopes::<S, String>(vec![])
+  }
+
+  /// Same as [`Scope::security`] with the scopes required by an `oauth2` or `openIdConnect` security.
+  pub fn security_with_scopes<S: ApiComponent, N: Into<String>>(mut self, scopes: Vec<N>) -> Self {
+    let names = S::security_requirement_names();
+    if !names.is_empty() {
+      let scopes = scopes.into_iter().map(Into::into).collect::<Vec<String>>();
+      self.securities.push(SecurityRequirement {
+        requirements: names.into_iter().map(|name| (name, scopes.clone())).collect(),
+      });
+    }
+    let security_schemes = S::securities()
+      .into_iter()
+      .map(|(name, security)| (name, ReferenceOr::Object(security)))
+      .collect::<BTreeMap<_, _>>();
+    if !security_schemes.is_empty() {
+      self.components.push(Components {
+        security_schemes,
+        ..Default::default()
+      });
+    }
+    self
+  }
+
+  /// Document the responses of the given error type on every operation of this scope, nested scopes included.
+  ///
+  /// Responses already documented by an operation for the same status code are kept.
+  pub fn default_responses<E: ApiErrorComponent>(mut self) -> Self {
+    self.default_responses.extend(E::error_responses());
+    let schemas = E::schemas_by_status_code().into_values().collect::<BTreeMap<_, _>>();
+    if !schemas.is_empty() {
+      self.components.push(Components {
+        schemas,
+        ..Default::default()
+      });
+    }
+    self
+  }
+
+  /// Document path parameters declared by this scope path using a path extractor, `Path<Uuid>` for `/orgs/{org_id}` for example.
+  ///
+  /// Those parameters are added to every operation of this scope which doesn't already extract them, whether services
+  /// are registered before or after this call.
+  pub fn path_parameters<P: ApiComponent>(mut self) -> Self {
+    let mut operation = Operation {
+      parameters: P::parameters().into_iter().map(ReferenceOr::Object).collect(),
+      ..Default::default()
+    };
+    operation.update_path_parameter_name_from_path(&self.path);
+    self.path_parameters.extend(
+      operation
+        .parameters
+        .into_iter()
+        .filter_map(|p| p.get_object())
+        .filter(|p| p._in == ParameterIn::Path),
+    );
+    let schemas = P::schema()
+      .into_iter()
+      .chain(P::child_schemas())
+      .collect::<BTreeMap<_, _>>();
+    if !schemas.is_empty() {
+      self.components.push(Components {
+        schemas,
+        ..Default::default()
+      });
+    }
+    self
+  }
+
   /// Proxy for [`actix_web::Scope::app_data`](https://docs.rs/actix-web/*/actix_web/struct.Scope.html#method.data).
   ///
   /// **NOTE:** This doesn't affect spec generation.
@@ -128,7 +254,8 @@ where
 
   /// Proxy for [`actix_web::web::Scope::wrap`](https://docs.rs/actix-web/*/actix_web/struct.Scope.html#method.wrap).
   ///
-  /// **NOTE:** This doesn't affect spec generation.
+  /// **NOTE:** Only middlewares wrapped in a [`DocumentedMiddleware`] affect spec generation, their effect being
+  /// documented on every operation of this scope.
   pub fn wrap<M, B>(
     mut self,
     mw: M,
@@ -139,17 +266,50 @@ where
   >
   where
     M: Transform<T::Service, ServiceRequest, Response = ServiceResponse<B>, Error = Error, InitError = ()> + 'static,
-    B: MessageBody,
+    T::Service: 'static,
+    B: MessageBody + 'static,
   {
+    self
+      .transforms
+      .extend(DocumentedMiddleware::<T::Service, B>::transform_of(&mw));
     Scope {
       item_map: self.item_map,
       components: self.components,
+      guard_documentation: self.guard_documentation,
+      transforms: self.transforms,
+      default_parameters: self.default_parameters,
+      servers: self.servers,
+      securities: self.securities,
+      default_responses: self.default_responses,
+      path_parameters: self.path_parameters,
       tags: self.tags,
       path: self.path,
       inner: self.inner.take().map(|s| s.wrap(mw)),
     }
   }
 
+  /// Wrapper for [`actix_web::web::Scope::wrap`](https://docs.rs/actix-web/*/actix_web/struct.Scope.html#method.wrap) documenting the middleware effect on every operation of this scope, shorthand for `wrap(DocumentedMiddleware::new(mw))`.
+  pub fn wrap_documented<M, B>(
+    self,
+    mw: M,
+  ) -> Scope<
+    actix_web::Scope<
+      impl ServiceFactory<ServiceRequest, Config = (), Response = ServiceResponse<B>, Error = Error, InitError = ()>,
+    >,
+  >
+  where
+    M: Transform<T::Service, ServiceRequest, Response = ServiceResponse<B>, Error = Error, InitError = ()>
+      + DocumentedTransform
+      + 'static,
+    M::Transform: 'static,
+    M::Future: 'static,
+    <M::Transform as Service<ServiceRequest>>::Future: 'static,
+    T::Service: 'static,
+    B: MessageBody + 'static,
+  {
+    self.wrap(DocumentedMiddleware::new(mw))
+  }
+
   /// Proxy for [`actix_web::web::Scope::wrap_fn`](https://docs.rs/actix-web/*/actix_web/struct.Scope.html#method.wrap_fn).
   ///
   /// **NOTE:** This doesn't affect spec generation.
@@ -168,6 +328,13 @@ where
     Scope {
       item_map: self.item_map,
       components: self.components,
+      guard_documentation: self.guard_documentation,
+      transforms: self.transforms,
+      default_parameters: self.default_parameters,
+      servers: self.servers,
+      securities: self.securities,
+      default_responses: self.default_responses,
+      path_parameters: self.path_parameters,
       tags: self.tags,
       path: self.path,
       inner: self.inner.take().map(|s| s.wrap_fn(mw)),
@@ -175,6 +342,7 @@ where
   }
 
   fn update_from_def_holder<D: DefinitionHolder>(&mut self, dh: &mut D) {
+    let scope_name = scope_name(&self.path);
     self.components.extend(dh.components());
     let mut item_map = IndexMap::new();
     dh.update_path_items(&mut item_map);
@@ -185,12 +353,20 @@ where
         .collect::<Vec<&str>>()
         .join("/");
 
-      for operation in path_item.operations.values_mut() {
+      for (operation_type, operation) in &mut path_item.item.operations {
         operation.update_path_parameter_name_from_path(&p);
+        if !scope_name.is_empty() {
+          path_item
+            .metadata
+            .entry(operation_type.clone())
+            .or_default()
+            .scope_names
+            .push(scope_name.clone());
+        }
         operation.tags.append(&mut self.tags.clone());
       }
 
-      self.item_map.insert(p, path_item);
+      self.item_map.entry(p).or_default().merge(path_item);
     }
   }
 }
@@ -1,12 +1,11 @@
 use crate::internal::actix::route::{Route, RouteWrapper};
-use crate::internal::definition_holder::DefinitionHolder;
+use crate::internal::definition_holder::{DefinitionHolder, DocumentedPathItem};
 use actix_web::dev::HttpServiceFactory;
 use apistos_models::components::Components;
-use apistos_models::paths::PathItem;
 use indexmap::IndexMap;
 
 pub struct ServiceConfig<'a> {
-  pub(crate) item_map: IndexMap<String, PathItem>,
+  pub(crate) item_map: IndexMap<String, DocumentedPathItem>,
   pub(crate) components: Vec<Components>,
   inner: &'a mut actix_web::web::ServiceConfig,
 }
@@ -1,49 +1,316 @@
-use apistos_models::paths::{Operation, ParameterIn};
-use once_cell::sync::Lazy;
-use regex::{Captures, Regex};
-use std::borrow::Cow;
+use actix_web::http::Method;
+use apistos_core::PathItemDefinition;
+use apistos_models::paths::{Operation, OperationType, Parameter, ParameterDefinition, ParameterIn, Response};
+use apistos_models::reference_or::ReferenceOr;
+use apistos_models::security::SecurityRequirement;
+use schemars::schema::{InstanceType, Schema, SchemaObject, SingleOrVec};
 
-/// Regex that can be used for fetching templated path parameters.
-#[allow(clippy::expect_used)]
-static PATH_TEMPLATE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\{(.*?)\}").expect("path template regex"));
+/// Pattern used by actix for tail matches (`{tail}*`).
+const TAIL_PATTERN: &str = ".*";
+
+/// Documented operation type for a given http method, `None` if the method can't be documented.
+pub(crate) fn operation_type_from_method(method: &Method) -> Option<OperationType> {
+  match method.as_str() {
+    "PUT" => Some(OperationType::Put),
+    "POST" => Some(OperationType::Post),
+    "DELETE" => Some(OperationType::Delete),
+    "OPTIONS" => Some(OperationType::Options),
+    "HEAD" => Some(OperationType::Head),
+    "PATCH" => Some(OperationType::Patch),
+    "TRACE" => Some(OperationType::Trace),
+    "GET" => Some(OperationType::Get),
+    _ => None,
+  }
+}
+
+/// A dynamic segment of an actix path template.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PathTemplateParameter {
+  pub(crate) name: String,
+  /// Custom regex of the segment, `{id:\d+}` for example.
+  pub(crate) pattern: Option<String>,
+  /// Whether the segment may match the remaining part of the path, `/` included.
+  pub(crate) tail: bool,
+}
+
+/// Parse an actix path template, returning the equivalent openapi path template (regex patterns and tail markers
+/// removed) along with the declared dynamic segments.
+pub(crate) fn parse_path_template(path: &str) -> (String, Vec<PathTemplateParameter>) {
+  let mut template = String::with_capacity(path.len());
+  let mut parameters = vec![];
+  let mut chars = path.chars().peekable();
+
+  while let Some(c) = chars.next() {
+    if c != '{' {
+      template.push(c);
+      continue;
+    }
+
+    // patterns may contain braces, e.g. `{id:\d{3}}`
+    let mut depth = 1;
+    let mut segment = String::new();
+    for c in chars.by_ref() {
+      match c {
+        '{' => depth += 1,
+        '}' => depth -= 1,
+        _ => {}
+      }
+      if depth == 0 {
+        break;
+      }
+      segment.push(c);
+    }
+
+    let (name, pattern) = match segment.split_once(':') {
+      Some((name, pattern)) => (name.trim().to_owned(), Some(pattern.to_owned())),
+      None => (segment.trim().to_owned(), None),
+    };
+    let tail_marker = chars.next_if_eq(&'*').is_some();
+    let pattern = pattern.or_else(|| tail_marker.then(|| TAIL_PATTERN.to_owned()));
+    let tail = tail_marker || matches!(pattern.as_deref(), Some(".*" | ".+"));
+
+    template.push('{');
+    template.push_str(&name);
+    template.push('}');
+    parameters.push(PathTemplateParameter { name, pattern, tail });
+  }
+
+  (template, parameters)
+}
+
+/// Name of a scope derived from its path, `/orgs/{org_id}/users` becoming `orgs/users`.
+pub(crate) fn scope_name(path: &str) -> String {
+  path
+    .split('/')
+    .filter(|segment| !segment.is_empty() && !segment.contains('{'))
+    .collect::<Vec<&str>>()
+    .join("/")
+}
+
+/// Openapi path template for a given actix path, `/items/{id:\d+}` becoming `/items/{id}`.
+pub(crate) fn openapi_path(path: &str) -> String {
+  parse_path_template(path).0
+}
+
+/// Crate private information about a documented operation, used while building the specification but never exposed
+/// in it.
+#[derive(Clone, Debug, Default)]
+pub struct OperationMetadata {
+  /// Name of the documented handler, used to generate operation ids.
+  pub(crate) handler_name: Option<String>,
+  /// Names of the scopes the operation is nested in, innermost first.
+  pub(crate) scope_names: Vec<String>,
+  /// Whether the operation is explicitly documented as not requiring any security.
+  pub(crate) security_disabled: bool,
+  /// Number of arguments documented as request body but left out, only the first one being documented.
+  pub(crate) ignored_request_bodies: usize,
+  /// Status codes of the responses documenting an extractor failure.
+  pub(crate) extractor_error_statuses: Vec<String>,
+}
+
+impl OperationMetadata {
+  pub(crate) fn new<P: PathItemDefinition>() -> Self {
+    Self {
+      handler_name: P::handler_name().map(ToOwned::to_owned),
+      scope_names: vec![],
+      security_disabled: P::security_disabled(),
+      ignored_request_bodies: P::ignored_request_bodies(),
+      extractor_error_statuses: P::extractor_error_statuses(),
+    }
+  }
+
+  /// Warning for operations with several arguments documented as request body, only the first one being kept.
+  pub(crate) fn request_body_conflict_warning(&self) -> Option<String> {
+    if self.ignored_request_bodies == 0 {
+      return None;
+    }
+    let handler = match &self.handler_name {
+      Some(handler_name) => format!("`{handler_name}` "),
+      None => String::new(),
+    };
+    Some(format!(
+      "handler {handler}has {} arguments documented as request body, only the first one is documented",
+      self.ignored_request_bodies + 1
+    ))
+  }
+}
 
 pub(crate) trait OperationUpdater {
+  fn add_missing_securities(&mut self, securities: &[SecurityRequirement]);
+  fn add_missing_responses(&mut self, responses: &[(String, Response)]);
   fn update_path_parameter_name_from_path(&mut self, path: &str);
+  fn add_missing_path_parameters(&mut self, parameters: &[Parameter]);
+  fn sort_path_parameters(&mut self, path: &str) -> Vec<String>;
 }
 
 impl OperationUpdater for Operation {
-  fn update_path_parameter_name_from_path(&mut self, path: &str) {
-    let mut param_names = vec![];
-    PATH_TEMPLATE_REGEX.replace_all(path, |c: &Captures| {
-      param_names.push(c[1].to_owned());
-      Into::<Cow<'static, str>>::into(":")
-    });
-    param_names.reverse();
+  /// Add securities declared at an upper level (a scope for example) unless the operation declares its own securities.
+  fn add_missing_securities(&mut self, securities: &[SecurityRequirement]) {
+    if self.security.is_none() && !securities.is_empty() {
+      self.security = Some(securities.to_vec());
+    }
+  }
 
-    for param in self
+  /// Add responses declared at an upper level (a scope for example) for status codes not yet documented by the operation.
+  fn add_missing_responses(&mut self, responses: &[(String, Response)]) {
+    for (status, response) in responses {
+      if !self.responses.responses.contains_key(status) {
+        self
+          .responses
+          .responses
+          .insert(status.clone(), ReferenceOr::Object(response.clone()));
+      }
+    }
+  }
+
+  /// Name unnamed path parameters from the given path template.
+  ///
+  /// Names already used by a parameter are skipped and remaining names are assigned starting from the end of the
+  /// template, so that the naming can be run again each time the path gets prefixed (by a scope for example).
+  fn update_path_parameter_name_from_path(&mut self, path: &str) {
+    let mut path_parameters = self
       .parameters
       .iter_mut()
       .filter_map(|p| p.get_object_mut())
       .filter(|p| p._in == ParameterIn::Path)
-    {
+      .collect::<Vec<&mut Parameter>>();
+    let used_names = path_parameters
+      .iter()
+      .filter(|p| !p.name.is_empty())
+      .map(|p| p.name.clone())
+      .collect::<Vec<String>>();
+    let (_, template_parameters) = parse_path_template(path);
+    let mut param_names = template_parameters
+      .iter()
+      .map(|p| p.name.clone())
+      .filter(|n| !used_names.contains(n))
+      .collect::<Vec<String>>();
+
+    for param in path_parameters.iter_mut().rev().filter(|p| p.name.is_empty()) {
       if let Some(n) = param_names.pop() {
-        if let Some((name, _pattern)) = n.split_once(':') {
-          param.name = name.to_string();
-        } else {
-          param.name = n;
-        }
+        param.name = n;
       } else {
         break;
       }
     }
+
+    for param in path_parameters {
+      if let Some(template_parameter) = template_parameters.iter().find(|p| p.name == param.name) {
+        document_path_template_parameter(param, template_parameter);
+      }
+    }
+  }
+
+  /// Order path parameters following the given (full) path template and report the ones which can't be matched to it.
+  fn sort_path_parameters(&mut self, path: &str) -> Vec<String> {
+    let (_, template_parameters) = parse_path_template(path);
+    let template_position = |name: &str| -> Option<usize> { template_parameters.iter().position(|p| p.name == name) };
+
+    let mut warnings = vec![];
+    let mut slots = vec![];
+    let mut path_parameters = vec![];
+    for (index, parameter) in self.parameters.iter().enumerate() {
+      if let ReferenceOr::Object(p) = parameter {
+        if p._in == ParameterIn::Path {
+          if p.name.is_empty() {
+            warnings.push("path parameter can't be matched to any segment of the path".to_owned());
+          } else if template_position(&p.name).is_none() {
+            warnings.push(format!(
+              "path parameter `{}` doesn't match any segment of the path",
+              p.name
+            ));
+          }
+          slots.push(index);
+          path_parameters.push(parameter.clone());
+        }
+      }
+    }
+    if !path_parameters.is_empty() {
+      for template_parameter in &template_parameters {
+        if !path_parameters
+          .iter()
+          .any(|p| matches!(p, ReferenceOr::Object(p) if p.name == template_parameter.name))
+        {
+          warnings.push(format!("path segment `{}` is not documented", template_parameter.name));
+        }
+      }
+    }
+
+    path_parameters.sort_by_key(|p| match p {
+      ReferenceOr::Object(p) => template_position(&p.name).unwrap_or(usize::MAX),
+      ReferenceOr::Reference { .. } => usize::MAX,
+    });
+    for (index, parameter) in slots.into_iter().zip(path_parameters) {
+      self.parameters[index] = parameter;
+    }
+
+    warnings
+  }
+
+  /// Add path parameters declared at an upper level (a scope for example) which are not yet declared by the operation.
+  fn add_missing_path_parameters(&mut self, parameters: &[Parameter]) {
+    let missing_parameters = parameters
+      .iter()
+      .filter(|parameter| {
+        !self
+          .parameters
+          .iter()
+          .filter_map(|p| match p {
+            ReferenceOr::Object(p) => Some(p),
+            ReferenceOr::Reference { .. } => None,
+          })
+          .any(|p| p._in == ParameterIn::Path && p.name == parameter.name)
+      })
+      .cloned()
+      .map(ReferenceOr::Object)
+      .collect::<Vec<ReferenceOr<Parameter>>>();
+    self.parameters.splice(0..0, missing_parameters);
+  }
+}
+
+/// Carry the segment regex to the parameter, anchored as actix matches the whole segment. `pattern` only applies to
+/// string (or untyped) schemas, the constraint is described in the parameter description for other schemas.
+fn document_path_template_parameter(parameter: &mut Parameter, template_parameter: &PathTemplateParameter) {
+  if template_parameter.tail && parameter.description.is_none() {
+    parameter.description = Some("Remaining part of the path, may contain `/`.".to_owned());
+  }
+
+  let Some(pattern) = &template_parameter.pattern else {
+    return;
+  };
+  let pattern = format!("^(?:{pattern})$");
+  match &mut parameter.definition {
+    Some(ParameterDefinition::Schema(ReferenceOr::Object(Schema::Object(schema)))) if is_string_schema(schema) => {
+      if schema.string().pattern.is_none() {
+        schema.string().pattern = Some(pattern);
+      }
+    }
+    _ => {
+      let constraint = format!("Path segment matching `{pattern}`.");
+      parameter.description = Some(match parameter.description.take() {
+        Some(description) => format!("{description} {constraint}"),
+        None => constraint,
+      });
+    }
+  }
+}
+
+fn is_string_schema(schema: &SchemaObject) -> bool {
+  match &schema.instance_type {
+    None => schema.reference.is_none(),
+    Some(SingleOrVec::Single(it)) => **it == InstanceType::String,
+    Some(SingleOrVec::Vec(its)) => its.contains(&InstanceType::String),
   }
 }
 
 #[cfg(test)]
 mod tests {
-  use crate::internal::actix::utils::OperationUpdater;
-  use apistos_models::paths::{Operation, Parameter, ParameterIn};
+  use crate::internal::actix::utils::{
+    document_path_template_parameter, parse_path_template, OperationMetadata, OperationUpdater, PathTemplateParameter,
+  };
+  use apistos_models::paths::{Operation, Parameter, ParameterDefinition, ParameterIn};
   use apistos_models::reference_or::ReferenceOr;
+  use schemars::schema::{InstanceType, Schema, SchemaObject};
 
   #[test]
   fn simple_path_parameter_name_replacement() {
@@ -102,4 +369,207 @@ mod tests {
     assert_eq!(first_parameter_name, "plop_id".to_string());
     assert_eq!(second_parameter_name, "clap_id".to_string());
   }
+
+  #[test]
+  fn path_parameter_name_replacement_with_prefix() {
+    let mut operation = Operation {
+      parameters: vec![
+        ReferenceOr::Object(Parameter {
+          name: "".to_string(),
+          _in: ParameterIn::Path,
+          ..Default::default()
+        }),
+        ReferenceOr::Object(Parameter {
+          name: "".to_string(),
+          _in: ParameterIn::Path,
+          ..Default::default()
+        }),
+      ],
+      ..Default::default()
+    };
+
+    operation.update_path_parameter_name_from_path("/users/{id:\\d+}");
+    operation.update_path_parameter_name_from_path("/orgs/{org_id}/users/{id:\\d+}");
+
+    let parameter_names = operation
+      .parameters
+      .iter()
+      .filter_map(|p| p.clone().get_object())
+      .map(|p| p.name)
+      .collect::<Vec<String>>();
+    assert_eq!(parameter_names, vec!["org_id".to_string(), "id".to_string()]);
+  }
+
+  #[test]
+  fn missing_path_parameters_added() {
+    let mut operation = Operation {
+      parameters: vec![ReferenceOr::Object(Parameter {
+        name: "id".to_string(),
+        _in: ParameterIn::Path,
+        ..Default::default()
+      })],
+      ..Default::default()
+    };
+
+    operation.add_missing_path_parameters(&[
+      Parameter {
+        name: "org_id".to_string(),
+        _in: ParameterIn::Path,
+        ..Default::default()
+      },
+      Parameter {
+        name: "id".to_string(),
+        _in: ParameterIn::Path,
+        ..Default::default()
+      },
+    ]);
+
+    let parameter_names = operation
+      .parameters
+      .iter()
+      .filter_map(|p| p.clone().get_object())
+      .map(|p| p.name)
+      .collect::<Vec<String>>();
+    assert_eq!(parameter_names, vec!["org_id".to_string(), "id".to_string()]);
+  }
+
+  #[test]
+  fn path_template_parsing() {
+    let (template, parameters) = parse_path_template(r"/items/{id:\d{3}}/{name}/files/{tail}*");
+
+    assert_eq!(template, "/items/{id}/{name}/files/{tail}".to_string());
+    assert_eq!(
+      parameters,
+      vec![
+        PathTemplateParameter {
+          name: "id".to_string(),
+          pattern: Some(r"\d{3}".to_string()),
+          tail: false,
+        },
+        PathTemplateParameter {
+          name: "name".to_string(),
+          pattern: None,
+          tail: false,
+        },
+        PathTemplateParameter {
+          name: "tail".to_string(),
+          pattern: Some(".*".to_string()),
+          tail: true,
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn path_template_pattern_documented() {
+    let template_parameter = PathTemplateParameter {
+      name: "id".to_string(),
+      pattern: Some(r"\d+".to_string()),
+      tail: false,
+    };
+    let parameter_with_type = |instance_type: InstanceType| Parameter {
+      name: "id".to_string(),
+      _in: ParameterIn::Path,
+      definition: Some(ParameterDefinition::Schema(ReferenceOr::Object(Schema::Object(
+        SchemaObject {
+          instance_type: Some(instance_type.into()),
+          ..Default::default()
+        },
+      )))),
+      ..Default::default()
+    };
+
+    let mut string_parameter = parameter_with_type(InstanceType::String);
+    document_path_template_parameter(&mut string_parameter, &template_parameter);
+    let pattern = match string_parameter.definition {
+      Some(ParameterDefinition::Schema(ReferenceOr::Object(schema))) => {
+        schema.into_object().string.and_then(|validation| validation.pattern)
+      }
+      _ => None,
+    };
+    assert_eq!(pattern.as_deref(), Some(r"^(?:\d+)$"));
+    assert_eq!(string_parameter.description, None);
+
+    let mut integer_parameter = parameter_with_type(InstanceType::Integer);
+    document_path_template_parameter(&mut integer_parameter, &template_parameter);
+    assert_eq!(
+      integer_parameter.description.as_deref(),
+      Some(r"Path segment matching `^(?:\d+)$`.")
+    );
+  }
+
+  #[test]
+  fn path_parameters_sorted_by_name() {
+    let mut operation = Operation {
+      parameters: vec![
+        ReferenceOr::Object(Parameter {
+          name: "user_id".to_string(),
+          _in: ParameterIn::Path,
+          ..Default::default()
+        }),
+        ReferenceOr::Object(Parameter {
+          name: "page".to_string(),
+          _in: ParameterIn::Query,
+          ..Default::default()
+        }),
+        ReferenceOr::Object(Parameter {
+          name: "org_id".to_string(),
+          _in: ParameterIn::Path,
+          ..Default::default()
+        }),
+      ],
+      ..Default::default()
+    };
+
+    let warnings = operation.sort_path_parameters("/orgs/{org_id}/users/{user_id}");
+
+    assert!(warnings.is_empty());
+    let parameter_names = operation
+      .parameters
+      .iter()
+      .filter_map(|p| p.clone().get_object())
+      .map(|p| p.name)
+      .collect::<Vec<String>>();
+    assert_eq!(
+      parameter_names,
+      vec!["org_id".to_string(), "page".to_string(), "user_id".to_string()]
+    );
+  }
+
+  #[test]
+  fn path_parameters_mismatch_reported() {
+    let mut operation = Operation {
+      parameters: vec![ReferenceOr::Object(Parameter {
+        name: "userId".to_string(),
+        _in: ParameterIn::Path,
+        ..Default::default()
+      })],
+      ..Default::default()
+    };
+
+    let warnings = operation.sort_path_parameters("/users/{user_id}");
+
+    assert_eq!(
+      warnings,
+      vec![
+        "path parameter `userId` doesn't match any segment of the path".to_string(),
+        "path segment `user_id` is not documented".to_string()
+      ]
+    );
+  }
+
+  #[test]
+  fn request_body_conflict_warning() {
+    let metadata = OperationMetadata {
+      handler_name: Some("create_pet".to_string()),
+      ignored_request_bodies: 2,
+      ..Default::default()
+    };
+
+    assert_eq!(
+      metadata.request_body_conflict_warning().as_deref(),
+      Some("handler `create_pet` has 3 arguments documented as request body, only the first one is documented")
+    );
+    assert_eq!(OperationMetadata::default().request_body_conflict_warning(), None);
+  }
 }
@@ -2,20 +2,75 @@ use crate::internal::actix::resource::Resource;
 use crate::internal::actix::route::RouteWrapper;
 use crate::internal::actix::scope::Scope;
 use crate::internal::actix::service_config::ServiceConfig;
+use crate::internal::actix::utils::{OperationMetadata, OperationUpdater};
+use crate::middleware;
+use crate::spec;
 use apistos_models::components::Components;
 use apistos_models::paths::{Operation, OperationType, PathItem};
+use apistos_models::server::Server;
 use indexmap::IndexMap;
 use std::mem;
 
-pub trait DefinitionHolder {
+/// Path item along with the crate private metadata of its operations, which is kept out of the public model so that
+/// it never ends up in the generated specification.
+#[derive(Default, Clone)]
+pub struct DocumentedPathItem {
+  pub(crate) item: PathItem,
+  pub(crate) metadata: IndexMap<OperationType, OperationMetadata>,
+}
+
+impl DocumentedPathItem {
+  /// Merge a documented path item into this one, operations and metadata declared later take precedence.
+  pub(crate) fn merge(&mut self, other: DocumentedPathItem) {
+    merge_path_item(&mut self.item, other.item);
+    self.metadata.extend(other.metadata);
+  }
+}
+
+mod sealed {
+  /// Restricts `DefinitionHolder` implementations to the wrappers of this crate.
+  pub trait Sealed {}
+
+  impl Sealed for super::RouteWrapper {}
+  impl Sealed for super::Resource {}
+  impl<T> Sealed for super::Scope<T> {}
+  impl<'a> Sealed for super::ServiceConfig<'a> {}
+}
+
+pub trait DefinitionHolder: sealed::Sealed {
   fn path(&self) -> &str;
   fn operations(&mut self) -> IndexMap<OperationType, Operation>;
+  fn operations_metadata(&mut self) -> IndexMap<OperationType, OperationMetadata> {
+    IndexMap::new()
+  }
   fn components(&mut self) -> Vec<Components>;
-  fn update_path_items(&mut self, path_op_map: &mut IndexMap<String, PathItem>) {
+  fn update_path_items(&mut self, path_op_map: &mut IndexMap<String, DocumentedPathItem>) {
     let ops = self.operations();
     if !ops.is_empty() {
       let op_map = path_op_map.entry(self.path().into()).or_default();
-      op_map.operations.extend(ops);
+      op_map.item.operations.extend(ops);
+      op_map.metadata.extend(self.operations_metadata());
+    }
+  }
+}
+
+/// Merge a path item into an existing one, operations and metadata declared later take precedence.
+pub(crate) fn merge_path_item(target: &mut PathItem, item: PathItem) {
+  if item.summary.is_some() {
+    target.summary = item.summary;
+  }
+  if item.description.is_some() {
+    target.description = item.description;
+  }
+  target.operations.extend(item.operations);
+  add_servers(target, item.server);
+  target.parameters.extend(item.parameters);
+}
+
+fn add_servers(target: &mut PathItem, servers: Vec<Server>) {
+  for server in servers {
+    if !target.server.iter().any(|s| s.url == server.url) {
+      target.server.push(server);
     }
   }
 }
@@ -29,6 +84,10 @@ impl DefinitionHolder for RouteWrapper {
     mem::take(&mut self.def.item.operations)
   }
 
+  fn operations_metadata(&mut self) -> IndexMap<OperationType, OperationMetadata> {
+    mem::take(&mut self.def.metadata)
+  }
+
   fn components(&mut self) -> Vec<Components> {
     mem::take(&mut self.component)
   }
@@ -43,8 +102,28 @@ impl DefinitionHolder for Resource {
     mem::take(&mut self.item_definition).unwrap_or_default().operations
   }
 
+  fn operations_metadata(&mut self) -> IndexMap<OperationType, OperationMetadata> {
+    mem::take(&mut self.operations_metadata)
+  }
+
   fn components(&mut self) -> Vec<Components> {
-    mem::take(&mut self.components)
+    let mut components = mem::take(&mut self.components);
+    components.extend(middleware::components(&self.transforms));
+    components
+  }
+
+  fn update_path_items(&mut self, path_op_map: &mut IndexMap<String, DocumentedPathItem>) {
+    let mut item = mem::take(&mut self.item_definition).unwrap_or_default();
+    self.guard_documentation.update_path_item(&mut item);
+    middleware::update_path_item(&self.transforms, &mut item);
+    spec::apply_default_parameters(&self.default_parameters, &self.path, &mut item);
+    if !item.operations.is_empty() {
+      let path_item = path_op_map.entry(self.path.clone()).or_default();
+      path_item.merge(DocumentedPathItem {
+        item,
+        metadata: self.operations_metadata(),
+      });
+    }
   }
 }
 
@@ -59,13 +138,28 @@ impl<T> DefinitionHolder for Scope<T> {
   }
 
   fn components(&mut self) -> Vec<Components> {
-    mem::take(&mut self.components)
+    let mut components = mem::take(&mut self.components);
+    components.extend(middleware::components(&self.transforms));
+    components
   }
 
-  fn update_path_items(&mut self, path_op_map: &mut IndexMap<String, PathItem>) {
-    for (path, item) in mem::take(&mut self.item_map) {
+  fn update_path_items(&mut self, path_op_map: &mut IndexMap<String, DocumentedPathItem>) {
+    for (path, mut documented_item) in mem::take(&mut self.item_map) {
+      let DocumentedPathItem { item, metadata } = &mut documented_item;
+      self.guard_documentation.update_path_item(item);
+      middleware::update_path_item(&self.transforms, item);
+      spec::apply_default_parameters(&self.default_parameters, &path, item);
+      add_servers(item, self.servers.clone());
+      for (operation_type, operation) in &mut item.operations {
+        // operations documented as not requiring any security don't inherit the scope securities
+        if !metadata.get(operation_type).map_or(false, |m| m.security_disabled) {
+          operation.add_missing_securities(&self.securities);
+        }
+        operation.add_missing_responses(&self.default_responses);
+        operation.add_missing_path_parameters(&self.path_parameters);
+      }
       let op_map = path_op_map.entry(path).or_default();
-      op_map.operations.extend(item.operations.into_iter());
+      op_map.merge(documented_item);
     }
   }
 }
@@ -84,10 +178,10 @@ impl<'a> DefinitionHolder for ServiceConfig<'a> {
     mem::take(&mut self.components)
   }
 
-  fn update_path_items(&mut self, path_op_map: &mut IndexMap<String, PathItem>) {
+  fn update_path_items(&mut self, path_op_map: &mut IndexMap<String, DocumentedPathItem>) {
     for (path, item) in mem::take(&mut self.item_map) {
       let op_map = path_op_map.entry(path).or_default();
-      op_map.operations.extend(item.operations.into_iter());
+      op_map.merge(item);
     }
   }
 }
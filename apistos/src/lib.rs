@@ -26,7 +26,7 @@
 //! use actix_web::http::StatusCode;
 //! use actix_web::middleware::Logger;
 //! use actix_web::web::Json;
-//! use apistos::actix::CreatedJson;
+//! use apistos::actix::{CreatedJson, StatusJson};
 //! use apistos::api_operation;
 //! use apistos::ApiComponent;
 //! use apistos::ApiErrorComponent;
@@ -81,7 +81,7 @@
 //! pub(crate) async fn test(
 //!   body: Json<Test>,
 //! ) -> Result<CreatedJson<Test>, ErrorResponse> {
-//!   Ok(CreatedJson(body.0))
+//!   Ok(StatusJson(body.0))
 //! }
 //!
 //! #[actix_web::main]
@@ -122,6 +122,7 @@
 //! | `query` (default) | Enables documenting `actix_web::web::Query`                              |                                                               |
 //! | `actix` (default) | Enables documenting types from `actix`                                   |                                                               |
 //! | `garde`           | Enables input validation through `garde`                                 | [`garde`](https://crates.io/crates/garde)                     |
+//! | `httpauth`        | Enables documenting extractors from `actix-web-httpauth`                 | [`actix-web-httpauth`](https://crates.io/crates/actix-web-httpauth) |
 //! | `qs_query`        | Enables documenting types from `serde_qs`                                | [`serde_qs`](https://crates.io/crates/serde-qs)               |
 //! | `chrono`          | Enables documenting types from `chrono`                                  | [`chrono`](https://crates.io/crates/chrono)                   |
 //! | `multipart`       | Enables documenting types from `actix-multipart`                         | [`actix-multipart`](https://crates.io/crates/actix-multipart) |
@@ -145,10 +146,21 @@
 
 pub use indexmap::IndexMap;
 
-pub use apistos_core::parameters::header::ApiHeader;
+#[cfg(feature = "httpauth")]
+pub use apistos_core::httpauth;
+pub use apistos_core::parameters::header::{
+  insert_response_header, response_header, response_header_component, ApiHeader,
+};
 pub use apistos_core::PathItemDefinition;
-pub use apistos_core::{ApiComponent, ApiErrorComponent, TypedSchema};
-pub use apistos_gen::{api_operation, ApiComponent, ApiCookie, ApiErrorComponent, ApiHeader, ApiSecurity, ApiType};
+pub use apistos_core::{
+  document_request_bodies, extract_credential, insert_response, override_media_types, success_responses,
+  validate_security, ApiComponent, ApiErrorComponent, ApiScopes, ApiSecurityFuture, ApiSecurityValidator, ApiTags,
+  TypedSchema,
+};
+pub use apistos_gen::{
+  api_operation, ApiComponent, ApiCookie, ApiErrorComponent, ApiHeader, ApiResponses, ApiScopes, ApiSecurity, ApiTags,
+  ApiType,
+};
 pub use apistos_models::*;
 
 mod internal;
@@ -156,5 +168,7 @@ mod internal;
 pub mod actix;
 
 pub mod app;
+pub mod guard;
+pub mod middleware;
 pub mod spec;
 pub mod web;
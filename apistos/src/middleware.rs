@@ -0,0 +1,120 @@
+//! Middleware contributing to the generated documentation.
+//!
+//! Middlewares implementing [`DocumentedTransform`] can be registered using `wrap` on [`App`](crate::app::App),
+//! [`Scope`](crate::web::Scope) and [`Resource`](crate::web::Resource) once wrapped in a [`DocumentedMiddleware`], or
+//! using the `wrap_documented` shorthand. Every operation served behind them is then updated to reflect what the
+//! middleware adds to the contract (required headers, security requirements, responses...).
+//!
+//! Other middlewares are registered using `wrap` as usual and don't affect the documentation.
+//!
+//! ```rust
+//! use apistos::middleware::DocumentedTransform;
+//! use apistos::paths::{Operation, Response};
+//! use apistos::reference_or::ReferenceOr;
+//!
+//! pub struct RateLimit;
+//!
+//! impl DocumentedTransform for RateLimit {
+//!   fn update_operation(&self, operation: &mut Operation) {
+//!     operation.responses.responses.insert(
+//!       "429".to_string(),
+//!       ReferenceOr::Object(Response {
+//!         description: "Too Many Requests".to_string(),
+//!         ..Default::default()
+//!       }),
+//!     );
+//!   }
+//! }
+//! ```
+
+use actix_service::boxed::{self, BoxFuture, BoxService};
+use actix_service::{Service, Transform};
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::Error;
+use apistos_models::components::Components;
+use apistos_models::paths::{Operation, PathItem};
+use std::any::Any;
+use std::rc::Rc;
+
+/// A middleware which can describe its effect on the operations it wraps.
+pub trait DocumentedTransform {
+  /// Update an operation served behind this middleware.
+  fn update_operation(&self, _operation: &mut Operation) {}
+
+  /// Components referenced by the documentation added in [`DocumentedTransform::update_operation`].
+  fn components(&self) -> Vec<Components> {
+    vec![]
+  }
+}
+
+type BoxedMiddlewareService<B> = BoxService<ServiceRequest, ServiceResponse<B>, Error>;
+type BoxedNewTransform<S, B> = Box<dyn Fn(S) -> BoxFuture<Result<BoxedMiddlewareService<B>, ()>>>;
+
+/// Middleware documenting its effect on the operations it wraps when registered using `wrap`, e.g.
+/// `.wrap(DocumentedMiddleware::new(RateLimit))`.
+///
+/// The wrapped middleware is shared with the documentation and its services are boxed so that `wrap` can recognize
+/// it whatever the wrapped middleware type. It is only recognized when given as is to `wrap`, not once nested in
+/// another middleware like `Condition`.
+pub struct DocumentedMiddleware<S, B> {
+  new_transform: BoxedNewTransform<S, B>,
+  transform: Rc<dyn DocumentedTransform>,
+}
+
+impl<S, B> DocumentedMiddleware<S, B>
+where
+  S: 'static,
+  B: 'static,
+{
+  /// Wrap a middleware documenting its effect using [`DocumentedTransform`].
+  pub fn new<M>(mw: M) -> Self
+  where
+    M: Transform<S, ServiceRequest, Response = ServiceResponse<B>, Error = Error, InitError = ()>
+      + DocumentedTransform
+      + 'static,
+    M::Transform: 'static,
+    M::Future: 'static,
+    <M::Transform as Service<ServiceRequest>>::Future: 'static,
+  {
+    let mw = Rc::new(mw);
+    let shared = mw.clone();
+    Self {
+      new_transform: Box::new(move |service| {
+        let transform = shared.new_transform(service);
+        Box::pin(async move { transform.await.map(boxed::service) })
+      }),
+      transform: mw,
+    }
+  }
+
+  /// Documentation of the wrapped middleware, if `mw` is a `DocumentedMiddleware`.
+  pub(crate) fn transform_of<M: 'static>(mw: &M) -> Option<Rc<dyn DocumentedTransform>> {
+    (mw as &dyn Any)
+      .downcast_ref::<Self>()
+      .map(|documented| documented.transform.clone())
+  }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for DocumentedMiddleware<S, B> {
+  type Response = ServiceResponse<B>;
+  type Error = Error;
+  type Transform = BoxedMiddlewareService<B>;
+  type InitError = ();
+  type Future = BoxFuture<Result<Self::Transform, Self::InitError>>;
+
+  fn new_transform(&self, service: S) -> Self::Future {
+    (self.new_transform)(service)
+  }
+}
+
+pub(crate) fn update_path_item(transforms: &[Rc<dyn DocumentedTransform>], path_item: &mut PathItem) {
+  for operation in path_item.operations.values_mut() {
+    for transform in transforms {
+      transform.update_operation(operation);
+    }
+  }
+}
+
+pub(crate) fn components(transforms: &[Rc<dyn DocumentedTransform>]) -> Vec<Components> {
+  transforms.iter().flat_map(|t| t.components()).collect()
+}
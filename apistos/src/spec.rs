@@ -1,9 +1,15 @@
 use schemars::schema::Schema;
+use serde::Serialize;
+use std::collections::BTreeMap;
 
 use apistos_core::ApiComponent;
+use apistos_models::components::Components;
 use apistos_models::info::Info;
-use apistos_models::paths::{ExternalDocumentation, Parameter};
+use apistos_models::paths::{
+  ExternalDocumentation, MediaType, Operation, OperationType, Parameter, ParameterIn, PathItem,
+};
 use apistos_models::reference_or::ReferenceOr;
+use apistos_models::security::{SecurityRequirement, SecurityScheme};
 use apistos_models::server::Server;
 use apistos_models::tag::Tag;
 
@@ -24,14 +30,208 @@ where
     DefaultParameters {
       parameters: T::parameters(),
       components,
+      filter: None,
     }
   }
 }
 
+/// Define an accessor for `DefaultSecurity`
+pub trait DefaultSecurityAccessor {
+  fn get_default_security() -> DefaultSecurity;
+}
+
+impl<T> DefaultSecurityAccessor for T
+where
+  T: ApiComponent,
+{
+  fn get_default_security() -> DefaultSecurity {
+    DefaultSecurity {
+      requirement: SecurityRequirement {
+        requirements: T::security_requirement_names()
+          .into_iter()
+          .map(|name| (name, vec![]))
+          .collect(),
+      },
+      security_schemes: T::securities(),
+    }
+  }
+}
+
+/// Define a security requirement applying to the whole api with its associated security schemes. Can be build from a
+/// type deriving `ApiSecurity` using `DefaultSecurityAccessor` trait.
+#[derive(Default, Clone)]
+pub struct DefaultSecurity {
+  pub requirement: SecurityRequirement,
+  pub security_schemes: BTreeMap<String, SecurityScheme>,
+}
+
+impl DefaultSecurity {
+  /// Scopes required by an `oauth2` or `openIdConnect` security.
+  pub fn with_scopes<N: Into<String>>(mut self, scopes: Vec<N>) -> Self {
+    let scopes = scopes.into_iter().map(Into::into).collect::<Vec<String>>();
+    for required_scopes in self.requirement.requirements.values_mut() {
+      required_scopes.clone_from(&scopes);
+    }
+    self
+  }
+}
+
 /// Define default parameters with there associated components. Can be build from a type implementing `ApiComponent` using `DefaultParameterAccessor` trait
+///
+/// Parameters are exposed as components keyed by location and name, for example `header.X-Request-Id`.
+#[derive(Default)]
 pub struct DefaultParameters {
   pub parameters: Vec<Parameter>,
   pub components: Vec<(String, ReferenceOr<Schema>)>,
+  /// Restrict the operations these parameters are added to, every operation by default.
+  pub filter: Option<DefaultParametersFilter>,
+}
+
+/// Operations matching every non empty criteria are selected.
+#[derive(Default, Clone)]
+pub struct DefaultParametersFilter {
+  /// Operations with a path starting with one of the given prefixes. For parameters declared on a scope or a resource, paths start with the scope or resource path.
+  pub path_prefixes: Vec<String>,
+  /// Operations with at least one of the given tags.
+  pub tags: Vec<String>,
+  /// Operations with one of the given methods.
+  pub methods: Vec<OperationType>,
+}
+
+impl DefaultParameters {
+  /// Restrict these parameters to the operations matching the given filter.
+  pub fn filtered(mut self, filter: DefaultParametersFilter) -> Self {
+    self.filter = Some(filter);
+    self
+  }
+
+  pub(crate) fn applies_to(&self, path: &str, operation_type: &OperationType, operation: &Operation) -> bool {
+    let Some(filter) = &self.filter else {
+      return true;
+    };
+    let path = path.trim_start_matches('/');
+    (filter.path_prefixes.is_empty()
+      || filter
+        .path_prefixes
+        .iter()
+        .any(|prefix| path.starts_with(prefix.trim_start_matches('/'))))
+      && (filter.tags.is_empty() || filter.tags.iter().any(|tag| operation.tags.contains(tag)))
+      && (filter.methods.is_empty() || filter.methods.contains(operation_type))
+  }
+
+  /// Parameter and schema components declared by these parameters.
+  pub(crate) fn to_components(&self) -> Components {
+    Components {
+      parameters: self
+        .parameters
+        .iter()
+        .map(|p| (parameter_component_key(p), ReferenceOr::Object(p.clone())))
+        .collect(),
+      schemas: self.components.iter().cloned().collect(),
+      ..Default::default()
+    }
+  }
+
+  /// Add references to these parameters to the given operation, skipping already referenced ones.
+  pub(crate) fn add_references(&self, operation: &mut Operation) {
+    for parameter in &self.parameters {
+      let _ref = format!("#/components/parameters/{}", parameter_component_key(parameter));
+      let already_referenced = operation
+        .parameters
+        .iter()
+        .any(|p| matches!(p, ReferenceOr::Reference { _ref: existing } if *existing == _ref));
+      if !already_referenced {
+        operation.parameters.push(ReferenceOr::Reference { _ref });
+      }
+    }
+  }
+}
+
+/// Add references to the matching default parameters to every operation of the given path item.
+pub(crate) fn apply_default_parameters(default_parameters: &[DefaultParameters], path: &str, item: &mut PathItem) {
+  for (operation_type, operation) in &mut item.operations {
+    for parameters in default_parameters {
+      if parameters.applies_to(path, operation_type, operation) {
+        parameters.add_references(operation);
+      }
+    }
+  }
+}
+
+/// Component key of a parameter, namespaced by location so that parameters with the same name don't collide.
+fn parameter_component_key(parameter: &Parameter) -> String {
+  let location = match parameter._in {
+    ParameterIn::Query => "query",
+    ParameterIn::Header => "header",
+    ParameterIn::Path => "path",
+    ParameterIn::Cookie => "cookie",
+  };
+  format!("{location}.{}", parameter.name)
+}
+
+/// Documentation of the responses answered when an extractor rejects a request, for example `415` for a `Json` payload
+/// sent with another content type or `404` for a `Path` which can't be parsed. Responses documented by the handler
+/// error type take precedence over these.
+#[derive(Default, Clone)]
+pub struct ExtractorErrors {
+  descriptions: BTreeMap<u16, String>,
+  schema: Option<String>,
+  components: Vec<(String, ReferenceOr<Schema>)>,
+}
+
+impl ExtractorErrors {
+  /// Replace the default description of extractor error responses with the given status code.
+  pub fn with_description(mut self, status: u16, description: impl Into<String>) -> Self {
+    self.descriptions.insert(status, description.into());
+    self
+  }
+
+  /// Document the body of every extractor error response using the schema of `T`.
+  pub fn with_schema<T: ApiComponent>(mut self) -> Self {
+    if let Some((name, schema)) = T::schema() {
+      self.schema = Some(name.clone());
+      self.components = vec![(name, schema)];
+      self.components.append(&mut T::child_schemas());
+    }
+    self
+  }
+
+  pub(crate) fn to_components(&self) -> Components {
+    Components {
+      schemas: self.components.iter().cloned().collect(),
+      ..Default::default()
+    }
+  }
+
+  /// Apply descriptions and schema to the extractor error responses of the given operation, documented with the given
+  /// status codes. Returns `true` if the shared schema is referenced.
+  pub(crate) fn update_responses(&self, operation: &mut Operation, extractor_error_statuses: &[String]) -> bool {
+    let mut schema_used = false;
+    for (status, response) in &mut operation.responses.responses {
+      let ReferenceOr::Object(response) = response else {
+        continue;
+      };
+      if !extractor_error_statuses.contains(status) {
+        continue;
+      }
+      if let Some(description) = status.parse::<u16>().ok().and_then(|s| self.descriptions.get(&s)) {
+        response.description.clone_from(description);
+      }
+      if let Some(schema) = &self.schema {
+        response.content.insert(
+          "application/json".to_owned(),
+          MediaType {
+            schema: Some(ReferenceOr::Reference {
+              _ref: format!("#/components/schemas/{schema}"),
+            }),
+            ..Default::default()
+          },
+        );
+        schema_used = true;
+      }
+    }
+    schema_used
+  }
 }
 
 #[derive(Default)]
@@ -44,6 +244,61 @@ pub struct Spec {
   pub external_docs: Option<ExternalDocumentation>,
   /// See more details at <https://spec.openapis.org/oas/latest.html#server-object>.
   pub servers: Vec<Server>,
+  /// Alternative security requirements applying to every operation, see [`DefaultSecurity`]. Security schemes are
+  /// registered as components. An operation can opt out using `#[api_operation(security = none)]`.
+  pub security: Vec<DefaultSecurity>,
   /// Default parameters to be added to each operation. This only serve for documentation purpose.
   pub default_parameters: Vec<DefaultParameters>,
+  /// Documentation of the responses answered when an extractor rejects a request.
+  pub extractor_errors: ExtractorErrors,
+  /// Strategy used to generate operation ids for operations without an explicit `operation_id`.
+  pub operation_id_strategy: OperationIdStrategy,
+  /// Tag groups exposed as `x-tagGroups`, see <https://redocly.com/docs/api-reference-docs/specification-extensions/x-tag-groups/>.
+  pub tag_groups: Vec<TagGroup>,
+  /// Tag operations after the scope they are declared in and group those tags by parent scope. The scope name is its
+  /// path without parameters, `/orgs/{org_id}/users` becoming `orgs/users`. Top level scopes are grouped under their
+  /// own name.
+  pub tags_from_scopes: bool,
+  /// Panic when building the app if a security requirement references an undeclared security scheme or oauth2 scope,
+  /// instead of logging a warning. Useful for requirements which can't be checked at compile time, like scopes given
+  /// as strings or typed scopes not feeding the referenced security scheme.
+  pub strict_security: bool,
+}
+
+/// A group of tags, see <https://redocly.com/docs/api-reference-docs/specification-extensions/x-tag-groups/>.
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct TagGroup {
+  pub name: String,
+  pub tags: Vec<String>,
+}
+
+/// Information available to generate an operation id.
+pub struct OperationIdContext<'a> {
+  /// Documented path, `/pets/{pet_id}` for example.
+  pub path: &'a str,
+  pub operation_type: &'a OperationType,
+  /// Name of the handler function when documented using `api_operation`.
+  pub handler_name: Option<&'a str>,
+  pub operation: &'a Operation,
+}
+
+/// Strategy used to generate operation ids.
+///
+/// Generated ids colliding with another operation id are suffixed with `_2`, `_3`... following the declaration order
+/// and a warning is logged.
+#[derive(Default)]
+pub enum OperationIdStrategy {
+  /// `{method}_{resource}-{md5 of path}`, for example `get_pets-9b2a1f...`.
+  #[default]
+  Hash,
+  /// Handler function name, for example `find_pets`. Falls back to [`OperationIdStrategy::MethodPath`] for operations
+  /// without known handler.
+  HandlerName,
+  /// Method and path segments in camelCase, for example `getPetsByPetId` for `GET /pets/{pet_id}`.
+  MethodPath,
+  /// First tag and handler function name, for example `pet.find_pets`. Falls back to
+  /// [`OperationIdStrategy::HandlerName`] for operations without tag.
+  TagHandler,
+  /// Custom generation.
+  Custom(Box<dyn Fn(&OperationIdContext<'_>) -> String>),
 }
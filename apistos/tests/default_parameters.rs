@@ -6,8 +6,8 @@ use actix_web::test::{call_service, init_service, try_read_body_json, TestReques
 use actix_web::web::{Header, Json, Path};
 use actix_web::{App, ResponseError};
 use apistos::app::OpenApiWrapper;
-use apistos::spec::{DefaultParameterAccessor, DefaultParameters, Spec};
-use apistos::web::{get, resource, scope};
+use apistos::spec::{DefaultParameterAccessor, DefaultParameters, DefaultParametersFilter, Spec};
+use apistos::web::{get, post, resource, scope};
 use apistos_gen::{api_operation, ApiComponent, ApiErrorComponent, ApiHeader};
 use apistos_models::info::Info;
 use apistos_models::paths::{OperationType, Parameter, ParameterIn};
@@ -97,7 +97,7 @@ async fn default_parameters() {
       required: Some(true),
       ..Default::default()
     }],
-    components: vec![],
+    ..Default::default()
   };
   let default_parameters = vec![
     default_parameters_macro,
@@ -148,9 +148,9 @@ async fn default_parameters() {
     vec![
       "plop_id".to_string(),
       "clap_name".to_string(),
-      "X-Env".to_string(),
-      "X-Env-Complex".to_string(),
-      "X-SomeParam".to_string()
+      "header.X-Env".to_string(),
+      "header.X-Env-Complex".to_string(),
+      "header.X-SomeParam".to_string()
     ]
   );
 
@@ -159,9 +159,9 @@ async fn default_parameters() {
   assert_eq!(
     parameter_components.keys().cloned().collect::<Vec<String>>(),
     vec![
-      "X-Env".to_string(),
-      "X-Env-Complex".to_string(),
-      "X-SomeParam".to_string()
+      "header.X-Env".to_string(),
+      "header.X-Env-Complex".to_string(),
+      "header.X-SomeParam".to_string()
     ]
   );
 
@@ -173,6 +173,127 @@ async fn default_parameters() {
   );
 }
 
+#[actix_web::test]
+async fn scoped_default_parameters() {
+  #[derive(Serialize, Deserialize, Debug, Clone, ApiErrorComponent)]
+  #[openapi_error(status(code = 405, description = "Invalid input"))]
+  pub(crate) enum ErrorResponse {
+    MethodNotAllowed(String),
+  }
+
+  impl Display for ErrorResponse {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
+      panic!()
+    }
+  }
+
+  impl ResponseError for ErrorResponse {
+    fn status_code(&self) -> StatusCode {
+      panic!()
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+  struct Test {
+    id_number: u32,
+    id_string: String,
+  }
+
+  #[allow(unused_tuple_struct_fields)]
+  #[derive(Clone, Debug, JsonSchema, ApiHeader)]
+  #[openapi_header(name = "X-Tenant", description = "Tenant the request applies to", required = true)]
+  struct TenantHeader(String);
+
+  #[allow(unused_tuple_struct_fields)]
+  #[derive(Clone, Debug, JsonSchema, ApiHeader)]
+  #[openapi_header(name = "X-Request-Id", description = "Request identifier")]
+  struct RequestIdHeader(String);
+
+  #[api_operation(tag = "tenant")]
+  pub(crate) async fn test(_body: Json<Test>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  #[api_operation(tag = "health")]
+  pub(crate) async fn health(_body: Json<Test>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  let openapi_path = "/test.json";
+
+  let request_id =
+    <Header<RequestIdHeader> as DefaultParameterAccessor>::get_default_parameter().filtered(DefaultParametersFilter {
+      methods: vec![OperationType::Post],
+      ..Default::default()
+    });
+  let tenant = <Header<TenantHeader> as DefaultParameterAccessor>::get_default_parameter();
+  let spec = Spec {
+    default_parameters: vec![request_id],
+    ..Default::default()
+  };
+  let app = App::new()
+    .document(spec)
+    .service(
+      scope("/tenants")
+        .default_parameters(tenant)
+        .service(resource("/items").route(get().to(test)).route(post().to(test))),
+    )
+    .service(resource("/health").route(get().to(health)).route(post().to(health)))
+    .build(openapi_path);
+  let app = init_service(app).await;
+
+  let req = TestRequest::get().uri(openapi_path).to_request();
+  let resp = call_service(&app, req).await;
+  assert!(resp.status().is_success());
+
+  let body: OpenApi = try_read_body_json(resp).await.expect("Unable to read body");
+  let names = |parameters: &[ReferenceOr<Parameter>]| {
+    parameters
+      .iter()
+      .map(|p| match p {
+        ReferenceOr::Object(obj) => obj.name.clone(),
+        ReferenceOr::Reference { _ref } => _ref.split('/').last().unwrap_or_default().to_string(),
+      })
+      .collect::<Vec<String>>()
+  };
+  let parameter_names = |path: &str, operation_type: OperationType| {
+    body
+      .paths
+      .paths
+      .get(path)
+      .and_then(|item| item.operations.get(&operation_type))
+      .map(|op| names(&op.parameters))
+      .unwrap_or_default()
+  };
+
+  // shared by every operation of the path, hence documented once on the path item
+  assert_eq!(
+    body
+      .paths
+      .paths
+      .get("/tenants/items")
+      .map(|item| names(&item.parameters))
+      .unwrap_or_default(),
+    vec!["header.X-Tenant".to_string()]
+  );
+  assert!(parameter_names("/tenants/items", OperationType::Get).is_empty());
+  assert_eq!(
+    parameter_names("/tenants/items", OperationType::Post),
+    vec!["header.X-Request-Id".to_string()]
+  );
+  assert!(parameter_names("/health", OperationType::Get).is_empty());
+  assert_eq!(
+    parameter_names("/health", OperationType::Post),
+    vec!["header.X-Request-Id".to_string()]
+  );
+
+  let parameter_components = body.components.map(|c| c.parameters).unwrap_or_default();
+  assert_eq!(
+    parameter_components.keys().cloned().collect::<Vec<String>>(),
+    vec!["header.X-Request-Id".to_string(), "header.X-Tenant".to_string()]
+  );
+}
+
 // Imports bellow aim at making clippy happy. Those dependencies are necessary for integration-test.
 use actix_service as _;
 use apistos_core as _;
@@ -0,0 +1,149 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::panic)]
+
+use actix_web::http::StatusCode;
+use actix_web::test::{call_service, init_service, try_read_body_json, TestRequest};
+use actix_web::web::{Json, Path};
+use actix_web::{App, ResponseError};
+use apistos::app::OpenApiWrapper;
+use apistos::spec::{ExtractorErrors, Spec};
+use apistos::web::{get, post, resource};
+use apistos_gen::{api_operation, ApiComponent, ApiErrorComponent};
+use apistos_models::paths::{OperationType, Response};
+use apistos_models::reference_or::ReferenceOr;
+use apistos_models::OpenApi;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+#[actix_web::test]
+async fn extractor_error_responses() {
+  #[derive(Serialize, Deserialize, Debug, Clone, ApiErrorComponent)]
+  #[openapi_error(
+    status(code = 400, description = "Pet is not valid"),
+    status(code = 405, description = "Invalid input")
+  )]
+  pub(crate) enum ErrorResponse {
+    BadRequest(String),
+    MethodNotAllowed(String),
+  }
+
+  impl Display for ErrorResponse {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
+      panic!()
+    }
+  }
+
+  impl ResponseError for ErrorResponse {
+    fn status_code(&self) -> StatusCode {
+      panic!()
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+  struct Test {
+    id_number: u32,
+    id_string: String,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+  struct ExtractorError {
+    message: String,
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn update_pet(_id: Path<u32>, _body: Json<Test>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn get_pet(_id: Path<u32>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  let openapi_path = "/test.json";
+
+  let spec = Spec {
+    extractor_errors: ExtractorErrors::default()
+      .with_description(415, "Only json is accepted")
+      .with_schema::<ExtractorError>(),
+    ..Default::default()
+  };
+  let app = App::new()
+    .document(spec)
+    .service(resource("/pets/{id}").route(post().to(update_pet)))
+    .service(resource("/pets/{id}/details").route(get().to(get_pet)))
+    .build(openapi_path);
+  let app = init_service(app).await;
+
+  let req = TestRequest::get().uri(openapi_path).to_request();
+  let resp = call_service(&app, req).await;
+  assert!(resp.status().is_success());
+
+  let body: OpenApi = try_read_body_json(resp).await.expect("Unable to read body");
+  let responses = |path: &str, operation_type: OperationType| -> BTreeMap<String, Response> {
+    body
+      .paths
+      .paths
+      .get(path)
+      .and_then(|item| item.operations.get(&operation_type))
+      .map(|op| {
+        op.responses
+          .responses
+          .iter()
+          .filter_map(|(status, response)| match response {
+            ReferenceOr::Object(response) => Some((status.clone(), response.clone())),
+            ReferenceOr::Reference { .. } => None,
+          })
+          .collect()
+      })
+      .unwrap_or_default()
+  };
+  let schema_ref = |response: &Response| {
+    response
+      .content
+      .get("application/json")
+      .and_then(|media_type| serde_json::to_value(&media_type.schema).ok())
+      .and_then(|schema| schema.get("$ref").and_then(Value::as_str).map(ToOwned::to_owned))
+  };
+
+  let update_responses = responses("/pets/{id}", OperationType::Post);
+  assert_eq!(
+    update_responses.keys().cloned().collect::<Vec<String>>(),
+    vec!["200", "400", "404", "405", "413", "415"]
+  );
+  // documented by the handler error
+  let bad_request = update_responses.get("400").cloned().unwrap_or_default();
+  assert_eq!(bad_request.description, "Pet is not valid");
+  assert_eq!(schema_ref(&bad_request), None);
+  // documented by the extractors
+  let unsupported_media_type = update_responses.get("415").cloned().unwrap_or_default();
+  assert_eq!(unsupported_media_type.description, "Only json is accepted");
+  assert!(unsupported_media_type.extensions.is_empty());
+  assert_eq!(
+    schema_ref(&unsupported_media_type).as_deref(),
+    Some("#/components/schemas/ExtractorError")
+  );
+  let not_found = update_responses.get("404").cloned().unwrap_or_default();
+  assert_eq!(not_found.description, "Not found");
+
+  let get_responses = responses("/pets/{id}/details", OperationType::Get);
+  assert_eq!(
+    get_responses.keys().cloned().collect::<Vec<String>>(),
+    vec!["200", "400", "404", "405"]
+  );
+
+  let schemas = body.components.map(|c| c.schemas).unwrap_or_default();
+  assert!(schemas.contains_key("ExtractorError"));
+}
+
+// Imports bellow aim at making clippy happy. Those dependencies are necessary for integration-test.
+use actix_service as _;
+use apistos_core as _;
+use indexmap as _;
+use log as _;
+use md5 as _;
+use once_cell as _;
+use regex as _;
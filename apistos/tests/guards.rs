@@ -0,0 +1,134 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::panic)]
+
+use actix_web::http::StatusCode;
+use actix_web::test::{call_service, init_service, try_read_body_json, TestRequest};
+use actix_web::web::Json;
+use actix_web::{App, ResponseError};
+use apistos::app::OpenApiWrapper;
+use apistos::guard;
+use apistos::spec::Spec;
+use apistos::web::{post, resource, scope};
+use apistos_gen::{api_operation, ApiComponent, ApiErrorComponent};
+use apistos_models::info::Info;
+use apistos_models::paths::OperationType;
+use apistos_models::OpenApi;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+#[actix_web::test]
+async fn documented_guards() {
+  #[derive(Serialize, Deserialize, Debug, Clone, ApiErrorComponent)]
+  #[openapi_error(status(code = 405, description = "Invalid input"))]
+  pub(crate) enum ErrorResponse {
+    MethodNotAllowed(String),
+  }
+
+  impl Display for ErrorResponse {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
+      panic!()
+    }
+  }
+
+  impl ResponseError for ErrorResponse {
+    fn status_code(&self) -> StatusCode {
+      panic!()
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+  struct Test {
+    id_number: u32,
+    id_string: String,
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn test(_body: Json<Test>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn test2(_body: Json<Test>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  let openapi_path = "/test.json";
+  let operation_path = "/test/line";
+  let operation_path2 = "/test/line2";
+
+  let info = Info {
+    title: "A well documented API".to_string(),
+    description: Some("Really well document I mean it".to_string()),
+    terms_of_service: Some("https://terms.com".to_string()),
+    ..Default::default()
+  };
+  let spec = Spec {
+    info: info.clone(),
+    ..Default::default()
+  };
+  let app = App::new()
+    .document(spec)
+    .service(
+      scope("test")
+        .guard_documented(guard::Host("api.netwo.io").scheme("https"))
+        .service(
+          resource("/line")
+            .guard_documented(guard::Any(guard::Get()).or(guard::Put()))
+            .to(test),
+        )
+        .service(
+          resource("/line2").route(
+            post()
+              .guard_documented(guard::Header("content-type", "application/xml"))
+              .to(test2),
+          ),
+        ),
+    )
+    .build(openapi_path);
+  let app = init_service(app).await;
+
+  let req = TestRequest::get().uri(openapi_path).to_request();
+  let resp = call_service(&app, req).await;
+  assert!(resp.status().is_success());
+
+  let body: OpenApi = try_read_body_json(resp).await.expect("Unable to read body");
+  let paths = body.paths.paths;
+
+  let path_item = paths.get(&operation_path.to_string()).cloned();
+  assert!(path_item.is_some());
+  let path_item = path_item.unwrap_or_default();
+  assert_eq!(
+    path_item.operations.keys().cloned().collect::<Vec<OperationType>>(),
+    vec![OperationType::Get, OperationType::Put]
+  );
+  assert_eq!(
+    path_item.server.iter().map(|s| s.url.clone()).collect::<Vec<String>>(),
+    vec!["https://api.netwo.io".to_string()]
+  );
+
+  let path_item2 = paths.get(&operation_path2.to_string()).cloned();
+  assert!(path_item2.is_some());
+  let operation2 = path_item2
+    .unwrap_or_default()
+    .operations
+    .get(&OperationType::Post)
+    .cloned()
+    .unwrap_or_default();
+  let content_types = operation2
+    .request_body
+    .and_then(|b| b.get_object())
+    .map(|b| b.content.keys().cloned().collect::<Vec<String>>())
+    .unwrap_or_default();
+  assert_eq!(content_types, vec!["application/xml".to_string()]);
+}
+
+// Imports bellow aim at making clippy happy. Those dependencies are necessary for integration-test.
+use actix_service as _;
+use apistos_core as _;
+use indexmap as _;
+use log as _;
+use md5 as _;
+use once_cell as _;
+use regex as _;
+use serde_json as _;
@@ -0,0 +1,203 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::panic)]
+
+use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::http::StatusCode;
+use actix_web::test::{call_service, init_service, try_read_body_json, TestRequest};
+use actix_web::web::Json;
+use actix_web::{App, Error, ResponseError};
+use apistos::app::OpenApiWrapper;
+use apistos::middleware::{DocumentedMiddleware, DocumentedTransform};
+use apistos::spec::Spec;
+use apistos::web::{get, resource, scope};
+use apistos_gen::{api_operation, ApiComponent, ApiErrorComponent};
+use apistos_models::components::Components;
+use apistos_models::info::Info;
+use apistos_models::paths::{Operation, OperationType, Parameter, ParameterDefinition, ParameterIn, Response};
+use apistos_models::reference_or::ReferenceOr;
+use apistos_models::OpenApi;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fmt::{Display, Formatter};
+use std::future::{ready, Ready};
+
+#[actix_web::test]
+async fn documented_middlewares() {
+  #[derive(Serialize, Deserialize, Debug, Clone, ApiErrorComponent)]
+  #[openapi_error(status(code = 405, description = "Invalid input"))]
+  pub(crate) enum ErrorResponse {
+    MethodNotAllowed(String),
+  }
+
+  impl Display for ErrorResponse {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
+      panic!()
+    }
+  }
+
+  impl ResponseError for ErrorResponse {
+    fn status_code(&self) -> StatusCode {
+      panic!()
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+  struct Test {
+    id_number: u32,
+    id_string: String,
+  }
+
+  struct RateLimit;
+
+  impl<S> Transform<S, ServiceRequest> for RateLimit
+  where
+    S: Service<ServiceRequest, Response = ServiceResponse, Error = Error>,
+  {
+    type Response = ServiceResponse;
+    type Error = Error;
+    type Transform = S;
+    type InitError = ();
+    type Future = Ready<Result<S, ()>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+      ready(Ok(service))
+    }
+  }
+
+  impl DocumentedTransform for RateLimit {
+    fn update_operation(&self, operation: &mut Operation) {
+      // bookkeeping used to build the specification isn't exposed to middlewares
+      assert!(operation.extensions.is_empty());
+      operation
+        .extensions
+        .insert("x-apistos-rate-limit".to_string(), json!(100));
+      operation.responses.responses.insert(
+        "429".to_string(),
+        ReferenceOr::Object(Response {
+          description: "Too Many Requests".to_string(),
+          ..Default::default()
+        }),
+      );
+    }
+  }
+
+  struct RequestId;
+
+  impl<S> Transform<S, ServiceRequest> for RequestId
+  where
+    S: Service<ServiceRequest, Response = ServiceResponse, Error = Error>,
+  {
+    type Response = ServiceResponse;
+    type Error = Error;
+    type Transform = S;
+    type InitError = ();
+    type Future = Ready<Result<S, ()>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+      ready(Ok(service))
+    }
+  }
+
+  impl DocumentedTransform for RequestId {
+    fn update_operation(&self, operation: &mut Operation) {
+      operation.parameters.push(ReferenceOr::Reference {
+        _ref: "#/components/parameters/X-Request-Id".to_string(),
+      });
+    }
+
+    fn components(&self) -> Vec<Components> {
+      let mut components = Components::default();
+      components.parameters.insert(
+        "X-Request-Id".to_string(),
+        ReferenceOr::Object(Parameter {
+          name: "X-Request-Id".to_string(),
+          _in: ParameterIn::Header,
+          definition: Some(ParameterDefinition::Schema(ReferenceOr::Object(
+            schemars::schema_for!(String).schema.into(),
+          ))),
+          ..Default::default()
+        }),
+      );
+      vec![components]
+    }
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn test(_body: Json<Test>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn test2(_body: Json<Test>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  let openapi_path = "/test.json";
+  let operation_path = "/test/line";
+  let operation_path2 = "/line2";
+
+  let info = Info {
+    title: "A well documented API".to_string(),
+    description: Some("Really well document I mean it".to_string()),
+    terms_of_service: Some("https://terms.com".to_string()),
+    ..Default::default()
+  };
+  let spec = Spec {
+    info: info.clone(),
+    ..Default::default()
+  };
+  let app = App::new()
+    .document(spec)
+    .service(
+      scope("test")
+        .wrap_documented(RateLimit)
+        .service(resource("/line").route(get().to(test))),
+    )
+    .service(resource("/line2").route(get().to(test2)))
+    .wrap(DocumentedMiddleware::new(RequestId))
+    .wrap(actix_web::middleware::Logger::default())
+    .build(openapi_path);
+  let app = init_service(app).await;
+
+  let req = TestRequest::get().uri(openapi_path).to_request();
+  let resp = call_service(&app, req).await;
+  assert!(resp.status().is_success());
+
+  let body: Value = try_read_body_json(resp).await.expect("Unable to read body");
+  assert_eq!(
+    body.pointer("/paths/~1test~1line/get/x-apistos-rate-limit"),
+    Some(&json!(100))
+  );
+  let body: OpenApi = serde_json::from_value(body).expect("Unable to deserialize spec");
+  let paths = body.paths.paths;
+
+  let operation = paths
+    .get(&operation_path.to_string())
+    .and_then(|p| p.operations.get(&OperationType::Get))
+    .cloned()
+    .unwrap_or_default();
+  assert!(operation.responses.responses.contains_key("429"));
+  assert_eq!(operation.parameters.len(), 1);
+
+  let operation2 = paths
+    .get(&operation_path2.to_string())
+    .and_then(|p| p.operations.get(&OperationType::Get))
+    .cloned()
+    .unwrap_or_default();
+  assert!(!operation2.responses.responses.contains_key("429"));
+  assert_eq!(operation2.parameters.len(), 1);
+
+  let components = body.components.unwrap_or_default();
+  assert!(components.parameters.contains_key("X-Request-Id"));
+  assert!(components.schemas.contains_key("Test"));
+}
+
+// Imports bellow aim at making clippy happy. Those dependencies are necessary for integration-test.
+use actix_service as _;
+use apistos_core as _;
+use indexmap as _;
+use log as _;
+use md5 as _;
+use once_cell as _;
+use regex as _;
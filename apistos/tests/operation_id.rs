@@ -6,7 +6,7 @@ use actix_web::test::{call_service, init_service, try_read_body_json, TestReques
 use actix_web::web::{Json, Path};
 use actix_web::{App, ResponseError};
 use apistos::app::OpenApiWrapper;
-use apistos::spec::Spec;
+use apistos::spec::{OperationIdStrategy, Spec};
 use apistos::web::{get, resource, scope};
 use apistos_gen::{api_operation, ApiComponent, ApiErrorComponent};
 use apistos_models::info::Info;
@@ -136,6 +136,131 @@ async fn default_operation_id() {
   assert_eq!(operation_id3, Some("test3".to_string()))
 }
 
+#[actix_web::test]
+async fn operation_id_strategies() {
+  #[derive(Serialize, Deserialize, Debug, Clone, ApiErrorComponent)]
+  #[openapi_error(status(code = 405, description = "Invalid input"))]
+  pub(crate) enum ErrorResponse {
+    MethodNotAllowed(String),
+  }
+
+  impl Display for ErrorResponse {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
+      panic!()
+    }
+  }
+
+  impl ResponseError for ErrorResponse {
+    fn status_code(&self) -> StatusCode {
+      panic!()
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+  struct Test {
+    id_number: u32,
+    id_string: String,
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn find_pet(_params: Path<u32>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  #[api_operation(operation_id = "find_pet")]
+  pub(crate) async fn find_pet_by_name(_params: Path<String>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  #[api_operation(summary = "List pets")]
+  pub(crate) async fn list_pets(_body: Json<Test>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  async fn operation_ids(strategy: OperationIdStrategy) -> Vec<Option<String>> {
+    let openapi_path = "/test.json";
+    let spec = Spec {
+      info: Info {
+        title: "A well documented API".to_string(),
+        ..Default::default()
+      },
+      operation_id_strategy: strategy,
+      ..Default::default()
+    };
+    let app = App::new()
+      .document(spec)
+      .service(
+        scope("/pets")
+          .service(resource("/{pet_id}").route(get().to(find_pet)))
+          .service(resource("/by-name/{name}").route(get().to(find_pet_by_name)))
+          .service(resource("/new/{pet_id}").route(get().to(find_pet)))
+          .service(resource("").route(get().to(list_pets))),
+      )
+      .build(openapi_path);
+    let app = init_service(app).await;
+
+    let req = TestRequest::get().uri(openapi_path).to_request();
+    let resp = call_service(&app, req).await;
+    assert!(resp.status().is_success());
+
+    let body: OpenApi = try_read_body_json(resp).await.expect("Unable to read body");
+    body
+      .paths
+      .paths
+      .values()
+      .map(|p| {
+        p.operations
+          .get(&OperationType::Get)
+          .and_then(|op| op.operation_id.clone())
+      })
+      .collect()
+  }
+
+  assert_eq!(
+    operation_ids(OperationIdStrategy::HandlerName).await,
+    vec![
+      Some("list_pets".to_string()),
+      Some("find_pet".to_string()),
+      Some("find_pet_2".to_string()),
+      Some("find_pet_3".to_string()),
+    ]
+  );
+  assert_eq!(
+    operation_ids(OperationIdStrategy::MethodPath).await,
+    vec![
+      Some("getPets".to_string()),
+      Some("find_pet".to_string()),
+      Some("getPetsNewByPetId".to_string()),
+      Some("getPetsByPetId".to_string()),
+    ]
+  );
+  assert_eq!(
+    operation_ids(OperationIdStrategy::TagHandler).await,
+    vec![
+      Some("list_pets".to_string()),
+      Some("find_pet".to_string()),
+      Some("pet.find_pet".to_string()),
+      Some("pet.find_pet_2".to_string()),
+    ]
+  );
+  assert_eq!(
+    operation_ids(OperationIdStrategy::Custom(Box::new(|context| {
+      format!(
+        "{:?}-{}",
+        context.operation_type,
+        context.handler_name.unwrap_or_default()
+      )
+    })))
+    .await,
+    vec![
+      Some("Get-list_pets".to_string()),
+      Some("find_pet".to_string()),
+      Some("Get-find_pet".to_string()),
+      Some("Get-find_pet_2".to_string()),
+    ]
+  );
+}
+
 // Imports bellow aim at making clippy happy. Those dependencies are necessary for integration-test.
 use actix_service as _;
 use apistos_core as _;
@@ -0,0 +1,140 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::panic)]
+
+use actix_web::http::StatusCode;
+use actix_web::test::{call_service, init_service, try_read_body_json, TestRequest};
+use actix_web::web::{Json, Path};
+use actix_web::{App, ResponseError};
+use apistos::app::OpenApiWrapper;
+use apistos::spec::Spec;
+use apistos::web::{delete, get, resource, scope};
+use apistos_gen::{api_operation, ApiComponent, ApiErrorComponent};
+use apistos_models::paths::OperationType;
+use apistos_models::reference_or::ReferenceOr;
+use apistos_models::server::Server;
+use apistos_models::OpenApi;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+#[actix_web::test]
+async fn path_item_metadata() {
+  #[derive(Serialize, Deserialize, Debug, Clone, ApiErrorComponent)]
+  #[openapi_error(status(code = 405, description = "Invalid input"))]
+  pub(crate) enum ErrorResponse {
+    MethodNotAllowed(String),
+  }
+
+  impl Display for ErrorResponse {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
+      panic!()
+    }
+  }
+
+  impl ResponseError for ErrorResponse {
+    fn status_code(&self) -> StatusCode {
+      panic!()
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+  struct Test {
+    id_number: u32,
+    id_string: String,
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn get_pet(_params: Path<u32>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn delete_pet(_params: Path<u32>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  let openapi_path = "/test.json";
+
+  let app = App::new()
+    .document(Spec::default())
+    .service(
+      scope("/v1")
+        .servers(vec![Server {
+          url: "https://v1.example.com".to_owned(),
+          ..Default::default()
+        }])
+        .service(
+          resource("/pets/{pet_id}")
+            .summary("A single pet")
+            .description("Operations on a pet")
+            .servers(vec![Server {
+              url: "https://pets.example.com".to_owned(),
+              ..Default::default()
+            }])
+            .route(get().to(get_pet))
+            .route(delete().to(delete_pet)),
+        )
+        .service(resource("/owners/{owner_id}").route(get().to(get_pet))),
+    )
+    .build(openapi_path);
+  let app = init_service(app).await;
+
+  let req = TestRequest::get().uri(openapi_path).to_request();
+  let resp = call_service(&app, req).await;
+  assert!(resp.status().is_success());
+
+  let body: OpenApi = try_read_body_json(resp).await.expect("Unable to read body");
+
+  let pet_item = body.paths.paths.get("/v1/pets/{pet_id}").cloned().unwrap_or_default();
+  assert_eq!(pet_item.summary.as_deref(), Some("A single pet"));
+  assert_eq!(pet_item.description.as_deref(), Some("Operations on a pet"));
+  assert_eq!(
+    pet_item.server.iter().map(|s| s.url.as_str()).collect::<Vec<&str>>(),
+    vec!["https://pets.example.com", "https://v1.example.com"]
+  );
+
+  // path parameters shared by every operation are documented once on the path item
+  let path_parameters = pet_item
+    .parameters
+    .iter()
+    .filter_map(|p| match p {
+      ReferenceOr::Object(obj) => Some(obj.name.clone()),
+      ReferenceOr::Reference { .. } => None,
+    })
+    .collect::<Vec<String>>();
+  assert_eq!(path_parameters, vec!["pet_id".to_string()]);
+  for operation_type in [OperationType::Get, OperationType::Delete] {
+    let operation = pet_item.operations.get(&operation_type).cloned().unwrap_or_default();
+    assert!(operation.parameters.is_empty());
+  }
+
+  // a single operation keeps its own parameters
+  let owner_item = body
+    .paths
+    .paths
+    .get("/v1/owners/{owner_id}")
+    .cloned()
+    .unwrap_or_default();
+  assert!(owner_item.summary.is_none());
+  assert!(owner_item.parameters.is_empty());
+  assert_eq!(
+    owner_item.server.iter().map(|s| s.url.as_str()).collect::<Vec<&str>>(),
+    vec!["https://v1.example.com"]
+  );
+  let owner_operation = owner_item
+    .operations
+    .get(&OperationType::Get)
+    .cloned()
+    .unwrap_or_default();
+  assert_eq!(owner_operation.parameters.len(), 1);
+}
+
+// Imports bellow aim at making clippy happy. Those dependencies are necessary for integration-test.
+use actix_service as _;
+use apistos_core as _;
+use indexmap as _;
+use log as _;
+use md5 as _;
+use once_cell as _;
+use regex as _;
+use serde_json as _;
@@ -128,6 +128,378 @@ async fn path_parameter_replacement() {
   );
 }
 
+#[actix_web::test]
+async fn scope_path_parameters() {
+  #[derive(Serialize, Deserialize, Debug, Clone, ApiErrorComponent)]
+  #[openapi_error(status(code = 405, description = "Invalid input"))]
+  pub(crate) enum ErrorResponse {
+    MethodNotAllowed(String),
+  }
+
+  impl Display for ErrorResponse {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
+      panic!()
+    }
+  }
+
+  impl ResponseError for ErrorResponse {
+    fn status_code(&self) -> StatusCode {
+      panic!()
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+  struct Test {
+    id_number: u32,
+    id_string: String,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+  struct TeamPath {
+    team_id: String,
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn test(_params: Path<(u32, String)>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn test2(_params: Path<TeamPath>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn test3(_params: Path<(u32, u64, String)>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  let openapi_path = "/test.json";
+
+  let spec = Spec {
+    info: Info {
+      title: "A well documented API".to_string(),
+      ..Default::default()
+    },
+    ..Default::default()
+  };
+  let app = App::new()
+    .document(spec)
+    .service(
+      scope("/orgs/{org_id}")
+        .path_parameters::<Path<u32>>()
+        .service(resource("/users/{id}").route(get().to(test)))
+        .service(resource("/teams/{team_id}").route(get().to(test2)))
+        .service(scope("/projects/{project_id}").service(resource("/items/{item_id}").route(get().to(test3)))),
+    )
+    .build(openapi_path);
+  let app = init_service(app).await;
+
+  let req = TestRequest::get().uri(openapi_path).to_request();
+  let resp = call_service(&app, req).await;
+  assert!(resp.status().is_success());
+
+  let body: OpenApi = try_read_body_json(resp).await.expect("Unable to read body");
+  let parameters = |path: &str| -> Vec<(String, Option<InstanceType>)> {
+    body
+      .paths
+      .paths
+      .get(path)
+      .and_then(|p| p.operations.get(&OperationType::Get))
+      .cloned()
+      .unwrap_or_default()
+      .parameters
+      .into_iter()
+      .filter_map(|p| p.get_object())
+      .map(|p| {
+        let instance_type = p.definition.and_then(|d| match d {
+          ParameterDefinition::Schema(ReferenceOr::Object(sch)) => match sch.into_object().instance_type {
+            Some(SingleOrVec::Single(it)) => Some(*it),
+            _ => None,
+          },
+          _ => None,
+        });
+        (p.name, instance_type)
+      })
+      .collect()
+  };
+
+  assert_eq!(
+    parameters("/orgs/{org_id}/users/{id}"),
+    vec![
+      ("org_id".to_string(), Some(InstanceType::Integer)),
+      ("id".to_string(), Some(InstanceType::String))
+    ]
+  );
+  assert_eq!(
+    parameters("/orgs/{org_id}/teams/{team_id}"),
+    vec![
+      ("org_id".to_string(), Some(InstanceType::Integer)),
+      ("team_id".to_string(), Some(InstanceType::String))
+    ]
+  );
+  assert_eq!(
+    parameters("/orgs/{org_id}/projects/{project_id}/items/{item_id}"),
+    vec![
+      ("org_id".to_string(), Some(InstanceType::Integer)),
+      ("project_id".to_string(), Some(InstanceType::Integer)),
+      ("item_id".to_string(), Some(InstanceType::String))
+    ]
+  );
+}
+
+#[actix_web::test]
+async fn scope_path_parameters_declared_after_services() {
+  #[derive(Serialize, Deserialize, Debug, Clone, ApiErrorComponent)]
+  #[openapi_error(status(code = 405, description = "Invalid input"))]
+  pub(crate) enum ErrorResponse {
+    MethodNotAllowed(String),
+  }
+
+  impl Display for ErrorResponse {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
+      panic!()
+    }
+  }
+
+  impl ResponseError for ErrorResponse {
+    fn status_code(&self) -> StatusCode {
+      panic!()
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+  struct Test {
+    id_number: u32,
+    id_string: String,
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn test(_params: Path<(u32, String)>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn test2(_params: Path<String>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  let openapi_path = "/test.json";
+
+  let app = App::new()
+    .document(Spec::default())
+    .service(
+      scope("/orgs/{org_id}")
+        .service(resource("/users/{id}").route(get().to(test)))
+        .service(scope("/teams").service(resource("/{team_id}").route(get().to(test2))))
+        .path_parameters::<Path<u32>>(),
+    )
+    .build(openapi_path);
+  let app = init_service(app).await;
+
+  let req = TestRequest::get().uri(openapi_path).to_request();
+  let resp = call_service(&app, req).await;
+  assert!(resp.status().is_success());
+
+  let body: OpenApi = try_read_body_json(resp).await.expect("Unable to read body");
+  let parameters = |path: &str| -> Vec<String> {
+    body
+      .paths
+      .paths
+      .get(path)
+      .and_then(|p| p.operations.get(&OperationType::Get))
+      .cloned()
+      .unwrap_or_default()
+      .parameters
+      .into_iter()
+      .filter_map(|p| p.get_object())
+      .map(|p| p.name)
+      .collect()
+  };
+
+  assert_eq!(parameters("/orgs/{org_id}/users/{id}"), vec!["org_id", "id"]);
+  assert_eq!(parameters("/orgs/{org_id}/teams/{team_id}"), vec!["org_id", "team_id"]);
+}
+
+#[actix_web::test]
+async fn regex_path_parameters() {
+  #[derive(Serialize, Deserialize, Debug, Clone, ApiErrorComponent)]
+  #[openapi_error(status(code = 405, description = "Invalid input"))]
+  pub(crate) enum ErrorResponse {
+    MethodNotAllowed(String),
+  }
+
+  impl Display for ErrorResponse {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
+      panic!()
+    }
+  }
+
+  impl ResponseError for ErrorResponse {
+    fn status_code(&self) -> StatusCode {
+      panic!()
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+  struct Test {
+    id_number: u32,
+    id_string: String,
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn test(_params: Path<(String, String)>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  let openapi_path = "/test.json";
+
+  let spec = Spec {
+    info: Info {
+      title: "A well documented API".to_string(),
+      ..Default::default()
+    },
+    ..Default::default()
+  };
+  let app = App::new()
+    .document(spec)
+    .service(scope(r"/items/{id:[a-z]{3}}").service(resource("/files/{tail:.*}").route(get().to(test))))
+    .build(openapi_path);
+  let app = init_service(app).await;
+
+  let req = TestRequest::get().uri(openapi_path).to_request();
+  let resp = call_service(&app, req).await;
+  assert!(resp.status().is_success());
+
+  let body: OpenApi = try_read_body_json(resp).await.expect("Unable to read body");
+  assert_eq!(
+    body.paths.paths.keys().cloned().collect::<Vec<String>>(),
+    vec!["/items/{id}/files/{tail}".to_string()]
+  );
+
+  let parameters: Vec<(String, Option<String>, Option<String>)> = body
+    .paths
+    .paths
+    .get("/items/{id}/files/{tail}")
+    .and_then(|p| p.operations.get(&OperationType::Get))
+    .cloned()
+    .unwrap_or_default()
+    .parameters
+    .into_iter()
+    .filter_map(|p| p.get_object())
+    .map(|p| {
+      let pattern = p.definition.and_then(|d| match d {
+        ParameterDefinition::Schema(ReferenceOr::Object(sch)) => {
+          sch.into_object().string.and_then(|validation| validation.pattern)
+        }
+        _ => None,
+      });
+      (p.name, pattern, p.description)
+    })
+    .collect();
+
+  assert_eq!(
+    parameters,
+    vec![
+      ("id".to_string(), Some("^(?:[a-z]{3})$".to_string()), None),
+      (
+        "tail".to_string(),
+        Some("^(?:.*)$".to_string()),
+        Some("Remaining part of the path, may contain `/`.".to_string())
+      ),
+    ]
+  );
+}
+
+#[actix_web::test]
+async fn struct_path_parameters() {
+  #[derive(Serialize, Deserialize, Debug, Clone, ApiErrorComponent)]
+  #[openapi_error(status(code = 405, description = "Invalid input"))]
+  pub(crate) enum ErrorResponse {
+    MethodNotAllowed(String),
+  }
+
+  impl Display for ErrorResponse {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
+      panic!()
+    }
+  }
+
+  impl ResponseError for ErrorResponse {
+    fn status_code(&self) -> StatusCode {
+      panic!()
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+  struct Test {
+    id_number: u32,
+    id_string: String,
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+  struct Ids {
+    #[serde(rename = "userId")]
+    user_id: u32,
+    org_id: String,
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn test(_params: Path<Ids>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  let openapi_path = "/test.json";
+
+  let spec = Spec {
+    info: Info {
+      title: "A well documented API".to_string(),
+      ..Default::default()
+    },
+    ..Default::default()
+  };
+  let app = App::new()
+    .document(spec)
+    .service(scope("/orgs/{org_id}").service(resource("/users/{userId}").route(get().to(test))))
+    .build(openapi_path);
+  let app = init_service(app).await;
+
+  let req = TestRequest::get().uri(openapi_path).to_request();
+  let resp = call_service(&app, req).await;
+  assert!(resp.status().is_success());
+
+  let body: OpenApi = try_read_body_json(resp).await.expect("Unable to read body");
+  let parameters: Vec<(String, Option<InstanceType>)> = body
+    .paths
+    .paths
+    .get("/orgs/{org_id}/users/{userId}")
+    .and_then(|p| p.operations.get(&OperationType::Get))
+    .cloned()
+    .unwrap_or_default()
+    .parameters
+    .into_iter()
+    .filter_map(|p| p.get_object())
+    .map(|p| {
+      let instance_type = p.definition.and_then(|d| match d {
+        ParameterDefinition::Schema(ReferenceOr::Object(sch)) => match sch.into_object().instance_type {
+          Some(SingleOrVec::Single(it)) => Some(*it),
+          _ => None,
+        },
+        _ => None,
+      });
+      (p.name, instance_type)
+    })
+    .collect();
+
+  assert_eq!(
+    parameters,
+    vec![
+      ("org_id".to_string(), Some(InstanceType::String)),
+      ("userId".to_string(), Some(InstanceType::Integer))
+    ]
+  );
+}
+
 // Imports bellow aim at making clippy happy. Those dependencies are necessary for integration-test.
 use actix_service as _;
 use apistos_core as _;
@@ -0,0 +1,149 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::panic)]
+
+use actix_web::http::StatusCode;
+use actix_web::test::{call_service, init_service, try_read_body_json, TestRequest};
+use actix_web::web::{Json, Path};
+use actix_web::{App, HttpResponse, ResponseError};
+use apistos::actix::{CreatedJson, Location, WithHeader};
+use apistos::app::OpenApiWrapper;
+use apistos::spec::Spec;
+use apistos::web::{get, post, resource};
+use apistos_gen::{api_operation, ApiComponent, ApiErrorComponent, ApiHeader};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fmt::{Display, Formatter};
+
+#[actix_web::test]
+async fn response_headers() {
+  #[allow(unused_tuple_struct_fields)]
+  #[derive(JsonSchema, ApiHeader)]
+  #[openapi_header(name = "Retry-After", description = "Seconds to wait before retrying", component)]
+  struct RetryAfter(u32);
+
+  #[allow(unused_tuple_struct_fields)]
+  #[derive(JsonSchema, ApiHeader)]
+  #[openapi_header(name = "ETag", description = "Version of the pet", required = true)]
+  struct ETag(String);
+
+  #[derive(Serialize, Deserialize, Debug, Clone, ApiErrorComponent)]
+  #[openapi_error(status(code = 429, header = RetryAfter))]
+  pub(crate) enum ErrorResponse {
+    TooManyRequests(u32),
+  }
+
+  impl Display for ErrorResponse {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
+      panic!()
+    }
+  }
+
+  impl ResponseError for ErrorResponse {
+    fn status_code(&self) -> StatusCode {
+      panic!()
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+  struct Pet {
+    name: String,
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn create_pet(_body: Json<Pet>) -> Result<WithHeader<CreatedJson<Pet>, Location>, ErrorResponse> {
+    panic!()
+  }
+
+  #[api_operation(
+    tag = "pet",
+    response(status = 200, body = Pet, headers(ETag)),
+    response(status = 503, description = "Under maintenance", headers(RetryAfter))
+  )]
+  pub(crate) async fn get_pet(_id: Path<u32>) -> HttpResponse {
+    panic!()
+  }
+
+  let openapi_path = "/test.json";
+
+  let app = App::new()
+    .document(Spec::default())
+    .service(resource("/pets").route(post().to(create_pet)))
+    .service(resource("/pets/{id}").route(get().to(get_pet)))
+    .build(openapi_path);
+  let app = init_service(app).await;
+
+  let req = TestRequest::get().uri(openapi_path).to_request();
+  let resp = call_service(&app, req).await;
+  assert!(resp.status().is_success());
+
+  // read as json as references to header components can't be deserialized
+  let body: Value = try_read_body_json(resp).await.expect("Unable to read body");
+  let headers = |path: &str, method: &str, status: &str| -> Value {
+    body
+      .pointer(&format!(
+        "/paths/{}/{method}/responses/{status}/headers",
+        path.replace('/', "~1")
+      ))
+      .cloned()
+      .unwrap_or_default()
+  };
+
+  assert_eq!(
+    headers("/pets", "post", "201"),
+    json!({
+      "Location": {
+        "deprecated": false,
+        "description": "Url of the created resource",
+        "required": true,
+        "schema": {
+          "format": "uri-reference",
+          "type": "string"
+        },
+        "style": "simple"
+      }
+    })
+  );
+  let retry_after = json!({
+    "Retry-After": {
+      "$ref": "#/components/headers/Retry-After"
+    }
+  });
+  assert_eq!(headers("/pets", "post", "429"), retry_after);
+  assert_eq!(headers("/pets/{id}", "get", "503"), retry_after);
+  assert_eq!(
+    headers("/pets/{id}", "get", "200")
+      .get("ETag")
+      .and_then(|etag| etag.get("required")),
+    Some(&Value::Bool(true))
+  );
+
+  assert_eq!(
+    body.pointer("/components/headers").cloned().unwrap_or_default(),
+    json!({
+      "Retry-After": {
+        "deprecated": false,
+        "description": "Seconds to wait before retrying",
+        "required": false,
+        "schema": {
+          "format": "uint32",
+          "minimum": 0.0,
+          "title": "RetryAfter",
+          "type": "integer"
+        },
+        "style": "simple"
+      }
+    })
+  );
+  assert!(!body.to_string().contains("x-apistos"));
+}
+
+// Imports bellow aim at making clippy happy. Those dependencies are necessary for integration-test.
+use actix_service as _;
+use apistos_core as _;
+use apistos_models as _;
+use indexmap as _;
+use log as _;
+use md5 as _;
+use once_cell as _;
+use regex as _;
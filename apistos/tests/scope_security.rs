@@ -0,0 +1,166 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::panic)]
+
+use actix_web::http::StatusCode;
+use actix_web::test::{call_service, init_service, try_read_body_json, TestRequest};
+use actix_web::web::Json;
+use actix_web::{App, ResponseError};
+use apistos::app::OpenApiWrapper;
+use apistos::spec::Spec;
+use apistos::web::{get, post, resource, scope};
+use apistos_gen::{api_operation, ApiComponent, ApiErrorComponent, ApiSecurity};
+use apistos_models::paths::{Operation, OperationType};
+use apistos_models::reference_or::ReferenceOr;
+use apistos_models::OpenApi;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+#[actix_web::test]
+async fn scope_security_and_default_responses() {
+  #[derive(Serialize, Deserialize, Debug, Clone, ApiErrorComponent)]
+  #[openapi_error(
+    status(code = 403, description = "Not an admin of this user"),
+    status(code = 405, description = "Invalid input")
+  )]
+  pub(crate) enum ErrorResponse {
+    Forbidden(String),
+    MethodNotAllowed(String),
+  }
+
+  impl Display for ErrorResponse {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
+      panic!()
+    }
+  }
+
+  impl ResponseError for ErrorResponse {
+    fn status_code(&self) -> StatusCode {
+      panic!()
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone, ApiErrorComponent)]
+  #[openapi_error(
+    status(code = 401, description = "Missing or invalid token"),
+    status(code = 403, description = "Forbidden"),
+    status(code = 500, description = "Internal error")
+  )]
+  pub(crate) enum AdminError {
+    Unauthorized(String),
+    Forbidden(String),
+    Internal(String),
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+  struct Test {
+    id_number: u32,
+    id_string: String,
+  }
+
+  #[derive(ApiSecurity)]
+  #[openapi_security(
+    name = "admin_jwt",
+    scheme(security_type(api_key(name = "Authorization", api_key_in = "header")))
+  )]
+  struct AdminJwt;
+
+  #[api_operation(tag = "admin")]
+  pub(crate) async fn users(_body: Json<Test>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  #[api_operation(tag = "admin", security = none)]
+  pub(crate) async fn login(_body: Json<Test>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  #[api_operation(tag = "public")]
+  pub(crate) async fn health(_body: Json<Test>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  let openapi_path = "/test.json";
+
+  let app = App::new()
+    .document(Spec::default())
+    .service(
+      scope("/admin")
+        .security::<AdminJwt>()
+        .default_responses::<AdminError>()
+        .service(resource("/users").route(get().to(users)))
+        .service(resource("/login").route(post().to(login))),
+    )
+    .service(resource("/health").route(get().to(health)))
+    .build(openapi_path);
+  let app = init_service(app).await;
+
+  let req = TestRequest::get().uri(openapi_path).to_request();
+  let resp = call_service(&app, req).await;
+  assert!(resp.status().is_success());
+
+  let body: OpenApi = try_read_body_json(resp).await.expect("Unable to read body");
+  let operation = |path: &str, operation_type: OperationType| -> Operation {
+    body
+      .paths
+      .paths
+      .get(path)
+      .and_then(|item| item.operations.get(&operation_type))
+      .cloned()
+      .unwrap_or_default()
+  };
+  let security_names = |operation: &Operation| {
+    operation
+      .security
+      .iter()
+      .flatten()
+      .flat_map(|s| s.requirements.keys().cloned())
+      .collect::<Vec<String>>()
+  };
+  let response_description = |operation: &Operation, status: &str| match operation.responses.responses.get(status) {
+    Some(ReferenceOr::Object(response)) => Some(response.description.clone()),
+    _ => None,
+  };
+
+  let users = operation("/admin/users", OperationType::Get);
+  assert_eq!(security_names(&users), vec!["admin_jwt".to_string()]);
+  assert_eq!(
+    users.responses.responses.keys().cloned().collect::<Vec<String>>(),
+    vec!["200", "400", "401", "403", "405", "413", "415", "500"]
+  );
+  // responses documented by the operation win
+  assert_eq!(
+    response_description(&users, "403").as_deref(),
+    Some("Not an admin of this user")
+  );
+  assert_eq!(
+    response_description(&users, "401").as_deref(),
+    Some("Missing or invalid token")
+  );
+
+  let login = operation("/admin/login", OperationType::Post);
+  // explicitly documented as public
+  assert!(login.security.as_ref().is_some_and(Vec::is_empty));
+  assert!(login.extensions.is_empty());
+  assert!(login.responses.responses.contains_key("401"));
+
+  let health = operation("/health", OperationType::Get);
+  assert!(health.security.is_none());
+  assert!(!health.responses.responses.contains_key("401"));
+
+  let security_schemes = body.components.map(|c| c.security_schemes).unwrap_or_default();
+  assert_eq!(
+    security_schemes.keys().cloned().collect::<Vec<String>>(),
+    vec!["admin_jwt".to_string()]
+  );
+}
+
+// Imports bellow aim at making clippy happy. Those dependencies are necessary for integration-test.
+use actix_service as _;
+use apistos_core as _;
+use indexmap as _;
+use log as _;
+use md5 as _;
+use once_cell as _;
+use regex as _;
+use serde_json as _;
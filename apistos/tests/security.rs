@@ -0,0 +1,203 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::panic)]
+
+use actix_web::error::ErrorForbidden;
+use actix_web::http::StatusCode;
+use actix_web::test::{call_service, init_service, try_read_body_json, TestRequest};
+use actix_web::web::Json;
+use actix_web::{App, HttpRequest, ResponseError};
+use apistos::app::OpenApiWrapper;
+use apistos::spec::{DefaultSecurityAccessor, Spec};
+use apistos::web::{get, post, resource};
+use apistos::ApiSecurityValidator;
+use apistos_gen::{api_operation, ApiComponent, ApiErrorComponent, ApiSecurity};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fmt::{Display, Formatter};
+use std::future::{ready, Ready};
+
+#[actix_web::test]
+async fn spec_security() {
+  #[derive(Serialize, Deserialize, Debug, Clone, ApiErrorComponent)]
+  #[openapi_error(status(code = 405, description = "Invalid input"))]
+  pub(crate) enum ErrorResponse {
+    MethodNotAllowed(String),
+  }
+
+  impl Display for ErrorResponse {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
+      panic!()
+    }
+  }
+
+  impl ResponseError for ErrorResponse {
+    fn status_code(&self) -> StatusCode {
+      panic!()
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+  struct Test {
+    id_number: u32,
+    id_string: String,
+  }
+
+  #[derive(ApiSecurity)]
+  #[openapi_security(scheme(security_type(api_key(name = "api_key", api_key_in = "header"))))]
+  struct ApiKey;
+
+  #[derive(ApiSecurity)]
+  #[openapi_security(scheme(security_type(oauth2(flows(implicit(
+    authorization_url = "https://authorize.com",
+    scopes(scope = "pets:read", description = "Read pets"),
+  ))))))]
+  struct OAuth;
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn pets(_body: Json<Test>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  #[api_operation(tag = "auth", security = none)]
+  pub(crate) async fn login(_body: Json<Test>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  let openapi_path = "/test.json";
+
+  let spec = Spec {
+    security: vec![
+      ApiKey::get_default_security(),
+      OAuth::get_default_security().with_scopes(vec!["pets:read"]),
+    ],
+    ..Default::default()
+  };
+  let app = App::new()
+    .document(spec)
+    .service(resource("/pets").route(get().to(pets)))
+    .service(resource("/login").route(post().to(login)))
+    .build(openapi_path);
+  let app = init_service(app).await;
+
+  let req = TestRequest::get().uri(openapi_path).to_request();
+  let resp = call_service(&app, req).await;
+  assert!(resp.status().is_success());
+
+  let body: Value = try_read_body_json(resp).await.expect("Unable to read body");
+  assert_eq!(
+    body.get("security").cloned(),
+    Some(json!([{ "api_key": [] }, { "o_auth": ["pets:read"] }]))
+  );
+
+  let security_schemes = body
+    .pointer("/components/securitySchemes")
+    .and_then(Value::as_object)
+    .map(|schemes| schemes.keys().cloned().collect::<Vec<String>>())
+    .unwrap_or_default();
+  assert_eq!(security_schemes, vec!["api_key".to_string(), "o_auth".to_string()]);
+
+  // inherits the top level security
+  assert_eq!(body.pointer("/paths/~1pets/get/security"), None);
+  // explicitly public
+  assert_eq!(body.pointer("/paths/~1login/post/security").cloned(), Some(json!([])));
+}
+
+#[actix_web::test]
+async fn security_extractor() {
+  #[derive(Serialize, Deserialize, Debug, Clone, ApiErrorComponent)]
+  #[openapi_error(status(code = 405, description = "Invalid input"))]
+  pub(crate) enum ErrorResponse {
+    MethodNotAllowed(String),
+  }
+
+  impl Display for ErrorResponse {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
+      panic!()
+    }
+  }
+
+  impl ResponseError for ErrorResponse {
+    fn status_code(&self) -> StatusCode {
+      panic!()
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+  struct Test {
+    id_number: u32,
+    id_string: String,
+  }
+
+  #[derive(ApiSecurity)]
+  #[openapi_security(extractor, scheme(security_type(api_key(name = "api_key", api_key_in = "header"))))]
+  struct ApiKey(String);
+
+  impl ApiSecurityValidator for ApiKey {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn validate(_req: &HttpRequest, credential: String) -> Self::Future {
+      if credential == "secret" {
+        ready(Ok(ApiKey(credential)))
+      } else {
+        ready(Err(ErrorForbidden("Invalid api key")))
+      }
+    }
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn pets(body: Json<Test>, key: ApiKey) -> Result<Json<Test>, ErrorResponse> {
+    assert_eq!(key.0, "secret");
+    Ok(body)
+  }
+
+  let openapi_path = "/test.json";
+
+  let app = App::new()
+    .document(Spec::default())
+    .service(resource("/pets").route(post().to(pets)))
+    .build(openapi_path);
+  let app = init_service(app).await;
+
+  let req = TestRequest::get().uri(openapi_path).to_request();
+  let resp = call_service(&app, req).await;
+  assert!(resp.status().is_success());
+
+  let body: Value = try_read_body_json(resp).await.expect("Unable to read body");
+  assert_eq!(
+    body.pointer("/paths/~1pets/post/security").cloned(),
+    Some(json!([{ "api_key": [] }]))
+  );
+
+  let test = Test {
+    id_number: 1,
+    id_string: "1".to_owned(),
+  };
+  let call = |key: Option<&'static str>| {
+    let mut req = TestRequest::post().uri("/pets").set_json(test.clone());
+    if let Some(key) = key {
+      req = req.insert_header(("api_key", key));
+    }
+    req.to_request()
+  };
+
+  let resp = call_service(&app, call(None)).await;
+  assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+  let resp = call_service(&app, call(Some("wrong"))).await;
+  assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+  let resp = call_service(&app, call(Some("secret"))).await;
+  assert_eq!(resp.status(), StatusCode::OK);
+}
+
+// Imports bellow aim at making clippy happy. Those dependencies are necessary for integration-test.
+use actix_service as _;
+use apistos_core as _;
+use apistos_models as _;
+use indexmap as _;
+use log as _;
+use md5 as _;
+use once_cell as _;
+use regex as _;
@@ -0,0 +1,124 @@
+#![allow(clippy::expect_used)]
+#![allow(clippy::panic)]
+
+use actix_web::http::StatusCode;
+use actix_web::test::{call_service, init_service, try_read_body_json, TestRequest};
+use actix_web::web::{Json, Path};
+use actix_web::{App, ResponseError};
+use apistos::app::OpenApiWrapper;
+use apistos::spec::{Spec, TagGroup};
+use apistos::web::{get, resource, scope};
+use apistos_gen::{api_operation, ApiComponent, ApiErrorComponent};
+use apistos_models::info::Info;
+use apistos_models::paths::OperationType;
+use apistos_models::OpenApi;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fmt::{Display, Formatter};
+
+#[actix_web::test]
+async fn tag_groups() {
+  #[derive(Serialize, Deserialize, Debug, Clone, ApiErrorComponent)]
+  #[openapi_error(status(code = 405, description = "Invalid input"))]
+  pub(crate) enum ErrorResponse {
+    MethodNotAllowed(String),
+  }
+
+  impl Display for ErrorResponse {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
+      panic!()
+    }
+  }
+
+  impl ResponseError for ErrorResponse {
+    fn status_code(&self) -> StatusCode {
+      panic!()
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+  struct Test {
+    id_number: u32,
+    id_string: String,
+  }
+
+  #[api_operation(summary = "Get a user")]
+  pub(crate) async fn test(_params: Path<u32>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  #[api_operation(summary = "Get stats")]
+  pub(crate) async fn test2(_body: Json<Test>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  #[api_operation(tag = "pet")]
+  pub(crate) async fn test3(_params: Path<u32>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  let openapi_path = "/test.json";
+
+  let spec = Spec {
+    info: Info {
+      title: "A well documented API".to_string(),
+      ..Default::default()
+    },
+    tag_groups: vec![TagGroup {
+      name: "Pets".to_string(),
+      tags: vec!["pet".to_string()],
+    }],
+    tags_from_scopes: true,
+    ..Default::default()
+  };
+  let app = App::new()
+    .document(spec)
+    .service(
+      scope("/admin")
+        .service(scope("/users").service(resource("/{id}").route(get().to(test))))
+        .service(resource("/stats").route(get().to(test2))),
+    )
+    .service(scope("/pets/{pet_id}").service(resource("").route(get().to(test3))))
+    .build(openapi_path);
+  let app = init_service(app).await;
+
+  let req = TestRequest::get().uri(openapi_path).to_request();
+  let resp = call_service(&app, req).await;
+  assert!(resp.status().is_success());
+
+  let body: Value = try_read_body_json(resp).await.expect("Unable to read body");
+  assert_eq!(
+    body.get("x-tagGroups").cloned(),
+    Some(json!([
+      { "name": "Pets", "tags": ["pet"] },
+      { "name": "admin", "tags": ["admin", "users"] },
+      { "name": "pets", "tags": ["pets"] }
+    ]))
+  );
+
+  assert!(!body.to_string().contains("x-apistos"));
+
+  let body: OpenApi = serde_json::from_value(body).expect("Unable to read body");
+  let operation_tags = |path: &str| -> Vec<String> {
+    body
+      .paths
+      .paths
+      .get(path)
+      .and_then(|p| p.operations.get(&OperationType::Get))
+      .map(|op| op.tags.clone())
+      .unwrap_or_default()
+  };
+  assert_eq!(operation_tags("/admin/users/{id}"), vec!["users"]);
+  assert_eq!(operation_tags("/admin/stats"), vec!["admin"]);
+  assert_eq!(operation_tags("/pets/{pet_id}/"), vec!["pet", "pets"]);
+}
+
+// Imports bellow aim at making clippy happy. Those dependencies are necessary for integration-test.
+use actix_service as _;
+use apistos_core as _;
+use indexmap as _;
+use log as _;
+use md5 as _;
+use once_cell as _;
+use regex as _;
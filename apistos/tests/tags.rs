@@ -8,6 +8,7 @@ use actix_web::{App, ResponseError};
 use apistos::app::OpenApiWrapper;
 use apistos::spec::Spec;
 use apistos::web::{get, resource, tagged_resource, tagged_scope};
+use apistos::ApiTags;
 use apistos_gen::{api_operation, ApiComponent, ApiErrorComponent};
 use apistos_models::info::Info;
 use apistos_models::paths::OperationType;
@@ -140,6 +141,94 @@ async fn tags() {
   assert_eq!(tags, vec!["A super tag"]);
 }
 
+#[actix_web::test]
+async fn typed_tags() {
+  #[derive(Serialize, Deserialize, Debug, Clone, ApiErrorComponent)]
+  #[openapi_error(status(code = 405, description = "Invalid input"))]
+  pub(crate) enum ErrorResponse {
+    MethodNotAllowed(String),
+  }
+
+  impl Display for ErrorResponse {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
+      panic!()
+    }
+  }
+
+  impl ResponseError for ErrorResponse {
+    fn status_code(&self) -> StatusCode {
+      panic!()
+    }
+  }
+
+  #[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, ApiComponent)]
+  struct Test {
+    id_number: u32,
+    id_string: String,
+  }
+
+  #[derive(ApiTags)]
+  enum Tags {
+    /// Everything about your pets
+    #[openapi_tag(name = "pet")]
+    Pet,
+    /// Access to Petstore orders
+    #[openapi_tag(name = "store")]
+    Store,
+  }
+
+  #[api_operation(tag = Tags::Pet)]
+  pub(crate) async fn test(_params: Path<u32>) -> Result<Json<Test>, ErrorResponse> {
+    panic!()
+  }
+
+  let openapi_path = "/test.json";
+
+  let spec = Spec {
+    info: Info {
+      title: "A well documented API".to_string(),
+      ..Default::default()
+    },
+    tags: Tags::tags(),
+    ..Default::default()
+  };
+  let app = App::new()
+    .document(spec)
+    .service(tagged_scope("test", vec![Tags::Store]).service(resource("/{plop_id}").route(get().to(test))))
+    .build(openapi_path);
+  let app = init_service(app).await;
+
+  let req = TestRequest::get().uri(openapi_path).to_request();
+  let resp = call_service(&app, req).await;
+  assert!(resp.status().is_success());
+
+  let body: OpenApi = try_read_body_json(resp).await.expect("Unable to read body");
+  assert_eq!(
+    body.tags,
+    vec![
+      Tag {
+        name: "pet".to_owned(),
+        description: Some("Everything about your pets".to_owned()),
+        ..Default::default()
+      },
+      Tag {
+        name: "store".to_owned(),
+        description: Some("Access to Petstore orders".to_owned()),
+        ..Default::default()
+      },
+    ]
+  );
+
+  let operation = body
+    .paths
+    .paths
+    .get("/test/{plop_id}")
+    .and_then(|p| p.operations.get(&OperationType::Get))
+    .cloned()
+    .unwrap_or_default();
+  assert_eq!(operation.tags, vec!["pet", "store"]);
+}
+
 // Imports bellow aim at making clippy happy. Those dependencies are necessary for integration-test.
 use actix_service as _;
 use apistos_core as _;
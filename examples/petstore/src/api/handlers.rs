@@ -3,7 +3,7 @@ use crate::api::models::{OrganizationSlug, Pet, QueryStatus, QueryTag, Realm};
 use crate::api::security::ApiKey;
 use actix_web::web::{Header, Json, Path, Query};
 use actix_web::Error;
-use apistos::actix::{CreatedJson, NoContent};
+use apistos::actix::{CreatedJson, NoContent, StatusJson};
 use apistos::api_operation;
 use serde_qs::actix::QsQuery;
 use std::collections::HashMap;
@@ -22,7 +22,7 @@ pub(crate) async fn update_pet(body: Json<Pet>, _key: ApiKey) -> Result<Json<Pet
   error_code = 405
 )]
 pub(crate) async fn add_pet(body: Json<Pet>, _key: ApiKey) -> Result<CreatedJson<Pet>, ErrorResponse> {
-  Ok(CreatedJson(body.0))
+  Ok(StatusJson(body.0))
 }
 
 /// Find pet by ID
@@ -1,18 +1,24 @@
-use actix_web::dev::Payload;
-use actix_web::http::Error;
-use actix_web::{FromRequest, HttpRequest};
-use apistos::ApiSecurity;
+use actix_web::error::ErrorForbidden;
+use actix_web::HttpRequest;
+use apistos::{ApiSecurity, ApiSecurityValidator};
 use futures::future::{ready, Ready};
 
 #[derive(ApiSecurity)]
-#[openapi_security(scheme(security_type(api_key(name = "api_key", api_key_in = "header"))))]
+#[openapi_security(
+  extractor,
+  scheme(security_type(api_key(name = "api_key", api_key_in = "header")))
+)]
 pub struct ApiKey;
 
-impl FromRequest for ApiKey {
-  type Error = Error;
+impl ApiSecurityValidator for ApiKey {
+  type Error = actix_web::Error;
   type Future = Ready<Result<Self, Self::Error>>;
 
-  fn from_request(_req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
-    ready(Ok(ApiKey {}))
+  fn validate(_req: &HttpRequest, credential: String) -> Self::Future {
+    if credential.is_empty() {
+      ready(Err(ErrorForbidden("Invalid api key")))
+    } else {
+      ready(Ok(ApiKey {}))
+    }
   }
 }
@@ -1,6 +1,6 @@
 use actix_web::web::{Json, Path};
 use actix_web::Error;
-use apistos::actix::CreatedJson;
+use apistos::actix::{CreatedJson, StatusJson};
 use apistos::{api_operation, ApiComponent};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -32,7 +32,7 @@ pub(crate) async fn get_todo(todo_id: Path<Uuid>) -> Result<Json<Todo>, Error> {
 #[api_operation(summary = "Add a new element to the todo list")]
 pub(crate) async fn add_todo(body: Json<NewTodo>) -> Result<CreatedJson<Todo>, Error> {
   let new_todo = body.into_inner();
-  Ok(CreatedJson(Todo {
+  Ok(StatusJson(Todo {
     id: Uuid::new_v4(),
     title: new_todo.title,
     description: new_todo.description,